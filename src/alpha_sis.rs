@@ -0,0 +1,333 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::CodeSet;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Initial letter groups and their codes.  Only the first matching group
+/// at the start of the name is coded this way.
+const INITIALS: &[(&str, &str)] = &[
+    ("GF", "08"),
+    ("GM", "03"),
+    ("GN", "02"),
+    ("KN", "02"),
+    ("PF", "08"),
+    ("PN", "02"),
+    ("PS", "00"),
+    ("WR", "04"),
+    ("A", "1"),
+    ("E", "1"),
+    ("H", "2"),
+    ("I", "1"),
+    ("J", "3"),
+    ("O", "1"),
+    ("U", "1"),
+    ("W", "4"),
+    ("Y", "5"),
+];
+
+/// Letter groups and their codes.  A group with several codes is
+/// ambiguous, and the first code gives the primary code.  If the first
+/// group coded is ambiguous, each other code yields an alternate.
+const BASIC: &[(&str, &[&str])] = &[
+    ("SCH", &["6"]),
+    ("CZ", &["70", "6", "0"]),
+    ("CH", &["6", "70", "0"]),
+    ("CK", &["7", "6"]),
+    ("DS", &["0", "10"]),
+    ("DZ", &["0", "10"]),
+    ("TS", &["0", "10"]),
+    ("TZ", &["0", "10"]),
+    ("CI", &["0"]),
+    ("CY", &["0"]),
+    ("CE", &["0"]),
+    ("SH", &["6"]),
+    ("DG", &["7"]),
+    ("PH", &["8"]),
+    ("C", &["7", "6"]),
+    ("K", &["7", "6"]),
+    ("Z", &["0"]),
+    ("S", &["0"]),
+    ("D", &["1"]),
+    ("T", &["1"]),
+    ("N", &["2"]),
+    ("M", &["3"]),
+    ("R", &["4"]),
+    ("L", &["5"]),
+    ("J", &["6"]),
+    ("G", &["7"]),
+    ("Q", &["7"]),
+    ("X", &["7"]),
+    ("F", &["8"]),
+    ("V", &["8"]),
+    ("B", &["9"]),
+    ("P", &["9"]),
+];
+
+/// The code of a special initial group and the codes of each following
+/// letter group.
+type CodedGroups = (&'static str, Vec<&'static [&'static str]>);
+
+/// The Alpha Search Inquiry System (Alpha SIS) procedure.
+///
+/// Alpha SIS was developed by IBM for name searches in insurance and
+/// government record systems.  A name is coded as a string of digits: a
+/// special initial group, such as "KN" or "PS", or an initial vowel, is
+/// coded first, and a "0" is used if there is none.  The remaining letter
+/// groups are then coded from left to right, vowels are not coded, and
+/// repeated digits are collapsed.  The code is zero-padded and truncated
+/// to `max_code_len` digits.
+///
+/// Some letter groups, such as "CH" and "CZ", are ambiguous and have more
+/// than one code.  [`PhonicsEncoder::encode`] returns the primary code,
+/// which takes the first reading of each ambiguous group, and
+/// [`AlphaSis::encode_alternates`] also returns the alternate codes, which
+/// take each other reading of the first letter group coded after the
+/// special initial group.  Later ambiguous groups only take their first
+/// reading, so a name has at most three codes.
+///
+/// The Alpha SIS algorithm is only defined for inputs over the standard
+/// English alphabet, _i.e._, "A-Z".  Other characters are handled according
+/// to `policy`.
+///
+/// # References
+///
+/// IBM. _Alpha Search Inquiry System: General Information Manual._ 1973.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{AlphaSis, PhonicsEncoder};
+///
+/// let enc = AlphaSis::new();
+/// assert_eq!(enc.encode("Schmidt").unwrap(), "06310000000000");
+/// ```
+pub struct AlphaSis {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The Alpha SIS specification has a standard maximum length.  However, it may be reasonable
+    /// to use either a shorter or longer length, depending on the application.  The default
+    /// value for `max_code_len` is in [`AlphaSis::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`AlphaSis::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for AlphaSis {
+    fn new() -> AlphaSis {
+        AlphaSis {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            max_code_len: AlphaSis::MAX_CODE_LEN_DEFAULT,
+            policy: AlphaSis::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // Take the first reading of every letter group
+        match self.code_groups(source_string)? {
+            Some((initial, groups)) => Ok(self.finish_code(initial, &groups, None)),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn encode_all(&self, source_string: &str) -> Result<CodeSet, PhonicsError> {
+        let mut codes = self.encode_alternates(source_string)?.into_iter();
+        let primary = codes.next().unwrap_or_default();
+
+        Ok(CodeSet::with_alternates(primary, codes.collect()))
+    }
+}
+
+impl AlphaSis {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 14;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+
+    /// Encode a string given in `word` and return every alternate code or
+    /// error.
+    ///
+    /// The first code is the primary code, as returned by
+    /// [`PhonicsEncoder::encode`].  An empty string encodes to no codes.  The
+    /// same codes are returned as a [`CodeSet`] by
+    /// [`PhonicsEncoder::encode_all`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonics::{AlphaSis, PhonicsEncoder};
+    ///
+    /// let enc = AlphaSis::new();
+    /// assert_eq!(
+    ///     enc.encode_alternates("Christopher").unwrap(),
+    ///     vec!["06401840000000", "07040184000000", "04018400000000"]
+    /// );
+    /// ```
+    pub fn encode_alternates(&self, source_string: &str) -> Result<Vec<String>, PhonicsError> {
+        // First, code the letter groups, if there are any
+        let (initial, groups) = match self.code_groups(source_string)? {
+            Some(coded) => coded,
+            None => return Ok(Vec::new()),
+        };
+
+        // Vary the reading of the first letter group only
+        let mut codes = vec![self.finish_code(initial, &groups, None)];
+        if let Some(first) = groups.first() {
+            for dst in &first[1..] {
+                let code = self.finish_code(initial, &groups, Some(dst));
+                if !codes.contains(&code) {
+                    codes.push(code);
+                }
+            }
+        }
+
+        Ok(codes)
+    }
+
+    /// Return the code of the special initial group of `source_string`, and
+    /// the codes of each following letter group, or `None` if there is
+    /// nothing to encode.
+    fn code_groups(&self, source_string: &str) -> Result<Option<CodedGroups>, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Check if the return string is empty yet
+        if return_string.is_empty() {
+            return Ok(None);
+        }
+
+        // Code a special initial group, or 0 if there is none
+        let mut rest: &str = &return_string;
+        let initial = match INITIALS.iter().find(|(src, _)| rest.starts_with(src)) {
+            Some((src, dst)) => {
+                rest = &rest[src.len()..];
+                *dst
+            }
+            None => "0",
+        };
+
+        // Code each letter group, skipping the letters that are not coded
+        let mut groups = Vec::new();
+        while !rest.is_empty() {
+            match BASIC.iter().find(|(src, _)| rest.starts_with(src)) {
+                Some((src, dsts)) => {
+                    groups.push(*dsts);
+                    rest = &rest[src.len()..];
+                }
+                None => rest = &rest[1..],
+            }
+        }
+
+        Ok(Some((initial, groups)))
+    }
+
+    /// Return the code of `initial` and the first reading of each of
+    /// `groups`, or `first` in place of the first group's if given, with
+    /// repeated digits removed and zero-padded and truncated.
+    fn finish_code(&self, initial: &str, groups: &[&[&str]], first: Option<&str>) -> String {
+        let mut digits: Vec<char> = initial.chars().collect();
+        for (i, dsts) in groups.iter().enumerate() {
+            let dst = match first {
+                Some(first) if i == 0 => first,
+                _ => dsts[0],
+            };
+            digits.extend(dst.chars());
+        }
+
+        // Remove duplicate consecutive digits, then zero-pad and truncate
+        digits.dedup();
+        let mut return_string: String = digits.into_iter().collect();
+        for _ in 0..self.max_code_len {
+            return_string.push('0');
+        }
+        return_string.as_str()[0..self.max_code_len].to_string()
+    }
+}
+
+impl Configurable for AlphaSis {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if options.max_code_len == Some(0) {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "Alpha SIS codes are zero-padded and need a max_code_len of at least 1",
+            )));
+        }
+
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for AlphaSis {
+    fn algorithm(&self) -> &str {
+        "alpha_sis"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlphaSis, PhonicsEncoder};
+
+    #[test]
+    fn test_alpha_sis_default_max_code_len() {
+        let e = AlphaSis::new();
+
+        assert_eq!(e.max_code_len, AlphaSis::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_alpha_sis_default_policy() {
+        let e = AlphaSis::new();
+
+        assert_eq!(e.policy, AlphaSis::POLICY_DEFAULT);
+    }
+}
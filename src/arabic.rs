@@ -0,0 +1,291 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Romanized letter groups and their consonantal key symbols.  Vowels are
+/// not written in Arabic script and are dropped.
+const LATIN_FOLD: &[(&str, &str)] = &[
+    ("KH", "X"),
+    ("GH", "G"),
+    ("SH", "C"),
+    ("CH", "C"),
+    ("TH", "S"),
+    ("DH", "Z"),
+    ("DJ", "J"),
+    ("PH", "F"),
+    ("C", "K"),
+    ("G", "J"),
+    ("Q", "K"),
+    ("V", "W"),
+    ("A", ""),
+    ("E", ""),
+    ("I", ""),
+    ("O", ""),
+    ("U", ""),
+    ("'", ""),
+];
+
+/// Arabic letters and their consonantal key symbols.  The vowel carriers,
+/// hamza, and ʿayn are dropped, as are the short vowel marks and tatweel.
+const ARABIC_FOLD: &[(&str, &str)] = &[
+    ("ا", ""),
+    ("أ", ""),
+    ("إ", ""),
+    ("آ", ""),
+    ("ٱ", ""),
+    ("ء", ""),
+    ("ؤ", ""),
+    ("ئ", ""),
+    ("ى", ""),
+    ("ع", ""),
+    ("ب", "B"),
+    ("ت", "T"),
+    ("ث", "S"),
+    ("ج", "J"),
+    ("ح", "H"),
+    ("خ", "X"),
+    ("د", "D"),
+    ("ذ", "Z"),
+    ("ر", "R"),
+    ("ز", "Z"),
+    ("س", "S"),
+    ("ش", "C"),
+    ("ص", "S"),
+    ("ض", "D"),
+    ("ط", "T"),
+    ("ظ", "Z"),
+    ("غ", "G"),
+    ("ف", "F"),
+    ("ق", "K"),
+    ("ك", "K"),
+    ("ل", "L"),
+    ("م", "M"),
+    ("ن", "N"),
+    ("ه", "H"),
+    ("ة", "H"),
+    ("و", "W"),
+    ("ي", "Y"),
+    ("\u{0640}", ""),
+    ("\u{064B}", ""),
+    ("\u{064C}", ""),
+    ("\u{064D}", ""),
+    ("\u{064E}", ""),
+    ("\u{064F}", ""),
+    ("\u{0650}", ""),
+    ("\u{0651}", ""),
+    ("\u{0652}", ""),
+];
+
+/// An Arabic name key.
+///
+/// The Arabic encoder produces a consonantal key for Arabic personal names
+/// given either in Arabic script or in one of the common romanizations.
+/// Because short vowels are not written in Arabic script, vowels are
+/// dropped from romanized names, so "Mohammed", "Muhammad", "Mohamad", and
+/// "محمد" all encode to "MHMD".  Consonants that romanizations render in
+/// several ways are merged: "kh" is `X`, "sh" is `C`, "th" and "s" are `S`,
+/// "dh" and "z" are `Z`, "q" and "k" are `K`, and "g" and "j" are `J`.  A
+/// "w" or "y" is only kept at the start of a word, where it is a consonant.
+///
+/// The article "al-" or "el-", including its assimilated forms such as
+/// "ar-" and "ad-", is removed, and the "Abd" compounds are normalized, so
+/// "Abdul Rahman", "Abd al-Rahman", "Abdurrahman", and "عبد الرحمن" collide.
+/// A final "h" after a vowel, and the final "ة" and "ه" in Arabic script,
+/// are dropped.  Spaces, hyphens, and apostrophes separate words.  Other
+/// unknown characters are handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Arabic, PhonicsEncoder};
+///
+/// let enc = Arabic::new();
+/// assert_eq!(enc.encode("Muhammad").unwrap(), enc.encode("محمد").unwrap());
+/// ```
+pub struct Arabic {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The Arabic script detection regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    arabic_script_re: Regex,
+
+    /// The romanized "Abd" compound regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    latin_compound_re: Regex,
+
+    /// The romanized article regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    latin_article_re: Regex,
+
+    /// The romanized final "h" regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    latin_final_re: Regex,
+
+    /// The Arabic "Abd" compound regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    arabic_compound_re: Regex,
+
+    /// The Arabic article regular expression is precompiled at instance instantiation to speed
+    /// execution at run time.
+    arabic_article_re: Regex,
+
+    /// The Arabic final "ة" and "ه" regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    arabic_final_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Arabic::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Arabic::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Arabic {
+    fn new() -> Arabic {
+        Arabic {
+            special_characters_re: Regex::new(r"[^A-Z\x{0621}-\x{0652}\x{0671} '\-]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z\x{0621}-\x{0652}\x{0671} '\-]*").unwrap(),
+            arabic_script_re: Regex::new(r"[\x{0621}-\x{064A}\x{0671}]").unwrap(),
+            latin_compound_re: Regex::new(r"ABD[AEIOU]?L[ '\-]?").unwrap(),
+            latin_article_re: Regex::new(r"(^|[ '\-])([AE]L[ '\-]|[AEU][DNRSTZ]-)").unwrap(),
+            latin_final_re: Regex::new(r"([AEIOU])H($|[ '\-])").unwrap(),
+            arabic_compound_re: Regex::new(r"عبد ?ال").unwrap(),
+            arabic_article_re: Regex::new(r"(^|[ \-])ال").unwrap(),
+            arabic_final_re: Regex::new(r"[ةه]($|[ \-])").unwrap(),
+            max_code_len: Arabic::MAX_CODE_LEN_DEFAULT,
+            policy: Arabic::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        let return_string = if self.arabic_script_re.is_match(&return_string) {
+            // Normalize the "Abd" compounds, the article, and the final "h"
+            let return_string = self.arabic_compound_re.replace_all(&return_string, "عبد ");
+            let return_string = self.arabic_article_re.replace_all(&return_string, "${1}");
+            let return_string = self.arabic_final_re.replace_all(&return_string, "${1}");
+
+            utils::transliterate(&return_string, ARABIC_FOLD)
+        } else {
+            // Normalize the "Abd" compounds, the article, and the final "h"
+            let return_string = self.latin_compound_re.replace_all(&return_string, "ABD ");
+            let return_string = self.latin_article_re.replace_all(&return_string, "${1}");
+            let return_string = self.latin_final_re.replace_all(&return_string, "${1}${2}");
+
+            utils::transliterate(&return_string, LATIN_FOLD)
+        };
+
+        // Keep "w" and "y" only where they begin a word
+        let return_string: String = return_string
+            .split(&[' ', '-', '\''][..])
+            .map(|word| {
+                word.char_indices()
+                    .filter(|(i, c)| *i == 0 || (*c != 'W' && *c != 'Y'))
+                    .map(|(_, c)| c)
+                    .collect::<String>()
+            })
+            .collect();
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Arabic {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Arabic {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Arabic {
+    fn algorithm(&self) -> &str {
+        "arabic"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Arabic, PhonicsEncoder};
+
+    #[test]
+    fn test_arabic_default_max_code_len() {
+        let e = Arabic::new();
+
+        assert_eq!(e.max_code_len, Arabic::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_arabic_default_policy() {
+        let e = Arabic::new();
+
+        assert_eq!(e.policy, Arabic::POLICY_DEFAULT);
+    }
+}
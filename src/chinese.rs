@@ -0,0 +1,289 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::utils::{Context, Rule, RuleSet};
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Tone-marked vowels, tone numbers, and Wade-Giles aspiration marks, and
+/// their replacements.  The "ü" is read as "u", including when written
+/// "v".
+const TONES: &[(&str, &str)] = &[
+    ("Ā", "A"),
+    ("Á", "A"),
+    ("Ǎ", "A"),
+    ("À", "A"),
+    ("Ē", "E"),
+    ("É", "E"),
+    ("Ě", "E"),
+    ("È", "E"),
+    ("Ī", "I"),
+    ("Í", "I"),
+    ("Ǐ", "I"),
+    ("Ì", "I"),
+    ("Ō", "O"),
+    ("Ó", "O"),
+    ("Ǒ", "O"),
+    ("Ò", "O"),
+    ("Ū", "U"),
+    ("Ú", "U"),
+    ("Ǔ", "U"),
+    ("Ù", "U"),
+    ("Ü", "U"),
+    ("Ǖ", "U"),
+    ("Ǘ", "U"),
+    ("Ǚ", "U"),
+    ("Ǜ", "U"),
+    ("V", "U"),
+    ("1", ""),
+    ("2", ""),
+    ("3", ""),
+    ("4", ""),
+    ("5", ""),
+    ("'", ""),
+    ("‘", ""),
+    ("’", ""),
+];
+
+/// Common Cantonese surname romanizations and their pinyin spellings.
+const CANTONESE: &[(&str, &str)] = &[
+    ("AU", "OU"),
+    ("CHAN", "CHEN"),
+    ("CHEUNG", "ZHANG"),
+    ("CHOI", "CAI"),
+    ("CHOW", "ZHOU"),
+    ("CHOY", "CAI"),
+    ("FUNG", "FENG"),
+    ("HO", "HE"),
+    ("KWAN", "GUAN"),
+    ("KWOK", "GUO"),
+    ("LAM", "LIN"),
+    ("LAU", "LIU"),
+    ("LEE", "LI"),
+    ("LEUNG", "LIANG"),
+    ("MAK", "MAI"),
+    ("NG", "WU"),
+    ("POON", "PAN"),
+    ("SIU", "XIAO"),
+    ("TAM", "TAN"),
+    ("TSANG", "ZENG"),
+    ("TSE", "XIE"),
+    ("TSUI", "XU"),
+    ("WONG", "HUANG"),
+    ("WOO", "WU"),
+    ("YAU", "QIU"),
+    ("YEUNG", "YANG"),
+    ("YIP", "YE"),
+    ("YUEN", "YUAN"),
+];
+
+/// The pinyin and Wade-Giles folding rules, in the order they are applied.
+/// Aspirated and unaspirated initials are merged, since the Wade-Giles
+/// apostrophe is often omitted.
+const RULES: &[Rule] = &[
+    // Wade-Giles "tzu" and "ssu" are pinyin "zi" and "si"
+    Rule::new("TZU", "TZI"),
+    Rule::new("SSU", "SSI"),
+    Rule::new("SZU", "SZI"),
+    // Wade-Giles "j" is pinyin "r", told apart from pinyin "j" by the final
+    Rule::new("JA", "RA"),
+    Rule::new("JE", "RE"),
+    Rule::new("JO", "RO"),
+    Rule::new("JIH", "RIH"),
+    Rule::new("JUI", "RUI"),
+    Rule::new("JUNG", "RUNG"),
+    // Initials
+    Rule::new("TS", "Z"),
+    Rule::new("TZ", "Z"),
+    Rule::new("SS", "S"),
+    Rule::new("SZ", "S"),
+    Rule::new("HS", "X"),
+    Rule::new("ZH", "J"),
+    Rule::new("CH", "J"),
+    Rule::new("Q", "J"),
+    Rule::new("C", "Z"),
+    Rule::new("K", "G"),
+    Rule::new("P", "B"),
+    Rule::new("T", "D"),
+    // Finals
+    Rule::new("IH", "I").before(Context::Consonant),
+    Rule::new("IH", "I").before(Context::Boundary),
+    Rule::new("EH", "E").before(Context::Consonant),
+    Rule::new("EH", "E").before(Context::Boundary),
+    Rule::new("IEN", "IAN"),
+    Rule::new("UEI", "UI"),
+    Rule::new("UNG", "ONG"),
+];
+
+/// A Chinese name key.
+///
+/// The Chinese encoder accepts names in pinyin, with or without tone marks
+/// or tone numbers, in Wade-Giles, and in the common Cantonese
+/// romanizations, and folds them to a shared key.  Tone marks, tone
+/// numbers, and the Wade-Giles aspiration apostrophe are removed, and
+/// common Cantonese surnames, such as "Chan", "Wong", and "Ng", are replaced
+/// with their pinyin spellings.
+///
+/// Aspirated and unaspirated initials are then merged, so the pinyin "zh",
+/// "ch", "j", and "q", and the Wade-Giles "ch" all become `J`, "z", "c",
+/// "ts", and "tz" become `Z`, "x" and "hs" become `X`, and "g" and "k", "b"
+/// and "p", and "d" and "t" are merged.  The Wade-Giles finals "-ih",
+/// "-ieh", "-ien", "-uei", and "-ung" are rewritten as the pinyin "-i",
+/// "-ie", "-ian", "-ui", and "-ong".  The Wade-Giles "j" is the pinyin
+/// "r", and is read as `R` where the final is not one pinyin "j" takes,
+/// such as in "jen" and "jih", but "ju", "juan", and "jun" are read as
+/// pinyin.  Thus "Xu", "Xǔ", and "Hsü" encode to
+/// "XU", and "Zhang", "Chang", and "Cheung" to "JANG".
+///
+/// Spaces separate words, and only a whole word is read as a Cantonese
+/// surname, so that "Mao Tse-tung" and "Mao Zedong" collide.  Other
+/// unknown characters are handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Chinese, PhonicsEncoder};
+///
+/// let enc = Chinese::new();
+/// assert_eq!(enc.encode("Hsu").unwrap(), enc.encode("Xu").unwrap());
+/// ```
+pub struct Chinese {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The rewrite rules are compiled at instance instantiation to speed execution at run
+    /// time.
+    rules: RuleSet,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Chinese::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Chinese::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Chinese {
+    fn new() -> Chinese {
+        Chinese {
+            special_characters_re: Regex::new(r"[^A-ZĀÁǍÀĒÉĚÈĪÍǏÌŌÓǑÒŪÚǓÙÜǕǗǙǛ1-5'‘’ \-]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZĀÁǍÀĒÉĚÈĪÍǏÌŌÓǑÒŪÚǓÙÜǕǗǙǛ1-5'‘’ \-]*").unwrap(),
+            rules: RuleSet::new(RULES, "AEIOU"),
+            max_code_len: Chinese::MAX_CODE_LEN_DEFAULT,
+            policy: Chinese::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Remove the tones and aspiration marks
+        let return_string = utils::transliterate(&return_string, TONES);
+
+        // Replace Cantonese surnames, then fold each word
+        let mut return_string: String = return_string
+            .split(' ')
+            .map(|word| {
+                let word = word.replace('-', "");
+                match CANTONESE.iter().find(|(src, _)| *src == word) {
+                    Some((_, dst)) => self.rules.apply(dst),
+                    None => self.rules.apply(&word),
+                }
+            })
+            .collect();
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Chinese {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Chinese {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Chinese {
+    fn algorithm(&self) -> &str {
+        "chinese"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chinese, PhonicsEncoder};
+
+    #[test]
+    fn test_chinese_default_max_code_len() {
+        let e = Chinese::new();
+
+        assert_eq!(e.max_code_len, Chinese::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_chinese_default_policy() {
+        let e = Chinese::new();
+
+        assert_eq!(e.policy, Chinese::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,281 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+
+/// A phonetic code tagged with the algorithm and settings that produced it.
+///
+/// Codes from different algorithms, or from one algorithm with different
+/// settings, may look alike without meaning the same thing, such as a Lein
+/// code and a Soundex code for the same name.  A [`PhoneticCode`] carries
+/// the name of the algorithm, as listed in [`ALGORITHMS`](crate::ALGORITHMS),
+/// and the `max_code_len` of the encoder, or `None` for an encoder without
+/// one, such as [`Davidson`](crate::Davidson).
+///
+/// Codes are equal only if their tags and codes are equal, and they are
+/// ordered by tag and then by code, so they can be used as keys in maps and
+/// sets holding codes of any kind.  [`PhoneticCode::matches`] and
+/// [`PhoneticCode::try_cmp`] instead return
+/// [`PhonicsError::IncompatibleCodes`] when the tags differ.
+///
+/// A code is written as the algorithm, a "/" and the `max_code_len` if
+/// there is one, a ":", and the code, such as "lein/4:H343", and is read
+/// back the same way with [`str::parse`].
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Lein, PhoneticCode, PhonicsEncoder, Soundex, TaggedEncoder};
+///
+/// let lein = Lein::new().encode_code("Knuth").unwrap();
+/// let soundex = Soundex::new().encode_code("Knuth").unwrap();
+/// assert_eq!(lein.to_string(), "lein/4:K210");
+/// assert_eq!(lein, "lein/4:K210".parse::<PhoneticCode>().unwrap());
+/// assert!(lein.matches(&Lein::new().encode_code("Kant").unwrap()).unwrap());
+/// assert!(lein.matches(&soundex).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PhoneticCode {
+    algorithm: String,
+    max_code_len: Option<usize>,
+    code: String,
+}
+
+impl PhoneticCode {
+    /// Return the code `code` of the algorithm `algorithm` with the maximum
+    /// code length `max_code_len`, or an error if it is invalid.
+    ///
+    /// The algorithm must be named, without a ":" or "/", or
+    /// [`PhonicsError::InvalidCode`] is returned.  The code must not be
+    /// empty, or [`PhonicsError::EmptyInput`] is returned, and must not be
+    /// longer than a `max_code_len` other than zero, or
+    /// [`PhonicsError::InvalidCode`] is returned.
+    pub fn new(
+        algorithm: &str,
+        max_code_len: Option<usize>,
+        code: &str,
+    ) -> Result<PhoneticCode, PhonicsError> {
+        if algorithm.is_empty() {
+            return Err(PhonicsError::InvalidCode(String::from(
+                "the algorithm is not named",
+            )));
+        }
+        if algorithm.contains(&[':', '/'][..]) {
+            return Err(PhonicsError::InvalidCode(format!(
+                "the algorithm \"{}\" has a ':' or '/'",
+                algorithm
+            )));
+        }
+        if code.is_empty() {
+            return Err(PhonicsError::EmptyInput);
+        }
+        if let Some(max_code_len) = max_code_len {
+            let len = code.chars().count();
+            if max_code_len > 0 && len > max_code_len {
+                return Err(PhonicsError::InvalidCode(format!(
+                    "the {} code \"{}\" is longer than {} characters",
+                    algorithm, code, max_code_len
+                )));
+            }
+        }
+
+        Ok(PhoneticCode {
+            algorithm: algorithm.to_string(),
+            max_code_len,
+            code: code.to_string(),
+        })
+    }
+
+    /// Return the name of the algorithm that produced the code.
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// Return the maximum code length of the encoder that produced the code,
+    /// or `None` if it has none.
+    pub fn max_code_len(&self) -> Option<usize> {
+        self.max_code_len
+    }
+
+    /// Return the code without its tag.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Return whether `other` was produced by the same algorithm with the
+    /// same settings, so the codes can be compared.
+    pub fn is_compatible(&self, other: &PhoneticCode) -> bool {
+        self.algorithm == other.algorithm && self.max_code_len == other.max_code_len
+    }
+
+    /// Return whether the code is equal to `other`, or
+    /// [`PhonicsError::IncompatibleCodes`] if they were produced by different
+    /// algorithms or settings.
+    pub fn matches(&self, other: &PhoneticCode) -> Result<bool, PhonicsError> {
+        Ok(self.try_cmp(other)? == Ordering::Equal)
+    }
+
+    /// Return the ordering of the code and `other`, or
+    /// [`PhonicsError::IncompatibleCodes`] if they were produced by different
+    /// algorithms or settings.
+    pub fn try_cmp(&self, other: &PhoneticCode) -> Result<Ordering, PhonicsError> {
+        if !self.is_compatible(other) {
+            return Err(PhonicsError::IncompatibleCodes(format!(
+                "{} and {}",
+                Tag(self),
+                Tag(other)
+            )));
+        }
+
+        Ok(self.code.cmp(&other.code))
+    }
+}
+
+/// The algorithm and settings of a code, as written before the ":".
+struct Tag<'a>(&'a PhoneticCode);
+
+impl fmt::Display for Tag<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.max_code_len {
+            Some(max_code_len) => write!(f, "{}/{}", self.0.algorithm, max_code_len),
+            None => write!(f, "{}", self.0.algorithm),
+        }
+    }
+}
+
+impl fmt::Display for PhoneticCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", Tag(self), self.code)
+    }
+}
+
+impl FromStr for PhoneticCode {
+    type Err = PhonicsError;
+
+    /// Read a code written as by [`fmt::Display`], such as "lein/4:H343", and
+    /// check it as in [`PhoneticCode::new`].
+    fn from_str(s: &str) -> Result<PhoneticCode, PhonicsError> {
+        if s.is_empty() {
+            return Err(PhonicsError::EmptyInput);
+        }
+
+        let (tag, code) = s.split_once(':').ok_or_else(|| {
+            PhonicsError::InvalidCode(format!("\"{}\" has no ':' after the algorithm", s))
+        })?;
+        let (algorithm, max_code_len) = match tag.split_once('/') {
+            Some((algorithm, max_code_len)) => {
+                let max_code_len = max_code_len.parse::<usize>().map_err(|_| {
+                    PhonicsError::InvalidCode(format!(
+                        "\"{}\" is not a maximum code length",
+                        max_code_len
+                    ))
+                })?;
+                (algorithm, Some(max_code_len))
+            }
+            None => (tag, None),
+        };
+
+        PhoneticCode::new(algorithm, max_code_len, code)
+    }
+}
+
+/// A trait for phonetic encoders that tag their codes with the algorithm and
+/// settings that produced them.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{PhonicsEncoder, Soundex, SoundexVariant, TaggedEncoder};
+///
+/// let enc = Soundex::with_variant(SoundexVariant::SqlServer);
+/// assert_eq!(enc.algorithm(), "soundex_sql_server");
+/// assert_eq!(enc.encode_code("Tymczak").unwrap().to_string(), "soundex_sql_server/4:T522");
+/// ```
+pub trait TaggedEncoder: PhonicsEncoder {
+    /// Return the name of the algorithm, as listed in
+    /// [`ALGORITHMS`](crate::ALGORITHMS).
+    fn algorithm(&self) -> &str;
+
+    /// Return the maximum code length, or `None` if the encoder has none.
+    fn max_code_len(&self) -> Option<usize>;
+
+    /// Encode a string given in `word` and return the tagged code or error.
+    ///
+    /// If the string has nothing to encode, [`PhonicsError::EmptyInput`] is
+    /// returned.
+    fn encode_code(&self, word: &str) -> Result<PhoneticCode, PhonicsError> {
+        PhoneticCode::new(
+            self.algorithm(),
+            TaggedEncoder::max_code_len(self),
+            &self.encode(word)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PhoneticCode, PhonicsError};
+
+    #[test]
+    fn test_code_round_trip() {
+        for s in &[
+            "lein/4:H343",
+            "soundex/0:H4163",
+            "davidson:HRD J",
+            "x/3:a:b",
+        ] {
+            let code = s.parse::<PhoneticCode>().unwrap();
+
+            assert_eq!(code.to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn test_code_invalid() {
+        assert_eq!("".parse::<PhoneticCode>(), Err(PhonicsError::EmptyInput));
+        assert_eq!(
+            "lein/4:".parse::<PhoneticCode>(),
+            Err(PhonicsError::EmptyInput)
+        );
+        assert!(matches!(
+            "H343".parse::<PhoneticCode>(),
+            Err(PhonicsError::InvalidCode(_))
+        ));
+        assert!(matches!(
+            "lein/four:H343".parse::<PhoneticCode>(),
+            Err(PhonicsError::InvalidCode(_))
+        ));
+        assert!(matches!(
+            ":H343".parse::<PhoneticCode>(),
+            Err(PhonicsError::InvalidCode(_))
+        ));
+        assert!(matches!(
+            "lein/4:H3431".parse::<PhoneticCode>(),
+            Err(PhonicsError::InvalidCode(_))
+        ));
+    }
+}
@@ -0,0 +1,527 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::utils;
+use crate::utils::{Context, Position, Rule, RuleSet};
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+use crate::ALGORITHMS;
+
+/// A rewrite rule of a [`CustomDefinition`].
+///
+/// Every `pattern` is replaced with `replacement` where the letter before it
+/// matches `left`, the letter after it matches `right`, and the pattern is at
+/// `position` in its word.  The contexts are "vowel", "consonant", or
+/// "boundary", and the positions are "initial", "medial", or "final".  A
+/// context or position that is not given is not checked.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomRule {
+    /// The letters to replace.
+    pub pattern: String,
+
+    /// The letters to replace them with.
+    pub replacement: String,
+
+    /// The context required before the pattern.
+    pub left: Option<String>,
+
+    /// The context required after the pattern.
+    pub right: Option<String>,
+
+    /// The position the pattern must take in its word.
+    pub position: Option<String>,
+}
+
+/// A group of letters sharing a code in a [`CustomDefinition`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomCode {
+    /// The letters in the group.
+    pub letters: String,
+
+    /// The code of each letter in the group.
+    pub code: String,
+}
+
+/// The definition of a [`CustomEncoder`].
+///
+/// A definition is usually read from TOML with [`CustomEncoder::from_toml`]
+/// or from JSON with [`CustomEncoder::from_json`], and describes the steps of
+/// the encoder, which are applied in this order:
+///
+/// 1. If `normalize` is `true`, the input is folded to ASCII, so that "Ñúñez"
+///    becomes "Nunez".  Then the character policy is applied, and the input
+///    is uppercased and stripped of characters outside `alphabet`.
+/// 2. The `rules` are applied in order, with `vowels` as the vowels for the
+///    "vowel" and "consonant" contexts.
+/// 3. If `keep_first_letter` is `true`, the first letter is set aside and
+///    neither dropped nor coded.
+/// 4. The letters in `drop` are removed.
+/// 5. If `dedup` is "letters", repeated letters are collapsed.
+/// 6. The letters in each group of `codes` are replaced by its code.  Other
+///    letters are kept.
+/// 7. If `dedup` is "codes", repeated codes are collapsed.
+/// 8. The first letter is restored, and the code is padded with `pad`, if it
+///    is given, and truncated to `max_code_len` characters, unless
+///    `max_code_len` is zero.
+///
+/// Every field may be left out.  The defaults keep the letters "A-Z" and
+/// apply no other step.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomDefinition {
+    /// The name of the encoder, which tags its [`PhoneticCode`](crate::PhoneticCode)s and so
+    /// must not have a ":" or "/" or be one of [`ALGORITHMS`](crate::ALGORITHMS).
+    pub name: String,
+
+    /// Whether to fold the input to ASCII before encoding.
+    pub normalize: bool,
+
+    /// The handling of characters outside `alphabet`: "reject", "drop", or
+    /// "transliterate".
+    pub policy: CharacterPolicy,
+
+    /// The uppercase letters the encoder accepts.
+    pub alphabet: String,
+
+    /// The uppercase letters that are vowels to the rules.
+    pub vowels: String,
+
+    /// The rewrite rules, in the order they are applied.
+    pub rules: Vec<CustomRule>,
+
+    /// Whether the first letter is kept as it is.
+    pub keep_first_letter: bool,
+
+    /// The letters to remove.
+    pub drop: String,
+
+    /// The collapsing of repeated characters: "none", "letters", or
+    /// "codes".
+    pub dedup: String,
+
+    /// The letter groups and their codes.
+    pub codes: Vec<CustomCode>,
+
+    /// The character to pad the code with, if any.
+    pub pad: Option<String>,
+
+    /// The maximum length of the code, or zero for no truncation.
+    pub max_code_len: usize,
+}
+
+impl Default for CustomDefinition {
+    fn default() -> CustomDefinition {
+        CustomDefinition {
+            name: String::from("custom"),
+            normalize: false,
+            policy: CharacterPolicy::Drop,
+            alphabet: String::from("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+            vowels: String::from("AEIOU"),
+            rules: Vec::new(),
+            keep_first_letter: false,
+            drop: String::new(),
+            dedup: String::from("none"),
+            codes: Vec::new(),
+            pad: None,
+            max_code_len: 0,
+        }
+    }
+}
+
+/// The collapsing of repeated characters in a [`CustomEncoder`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Dedup {
+    None,
+    Letters,
+    Codes,
+}
+
+/// An encoder whose steps are given by a [`CustomDefinition`].
+///
+/// A custom encoder follows the same pipeline as [`Lein`](crate::Lein) and
+/// its relatives, with the letters, rules, and codes read from a definition
+/// rather than written in code, so that a phonetic key for a particular
+/// domain, such as product or drug names, can be built without changing the
+/// crate.  The definition is checked when the encoder is created, and an
+/// invalid definition returns [`PhonicsError::InvalidConfiguration`].
+///
+/// # Example
+///
+/// ```
+/// use phonics::{CustomEncoder, PhonicsEncoder};
+///
+/// let enc = CustomEncoder::from_toml(
+///     r#"
+///     name = "lein_like"
+///     keep_first_letter = true
+///     drop = "AEIOUYWH"
+///     dedup = "letters"
+///     pad = "0"
+///     max_code_len = 4
+///     codes = [
+///         { letters = "DT", code = "1" },
+///         { letters = "MN", code = "2" },
+///         { letters = "LR", code = "3" },
+///         { letters = "BFPV", code = "4" },
+///         { letters = "CJKGQSXZ", code = "5" },
+///     ]
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(enc.encode("Hilbert").unwrap(), "H343");
+/// ```
+pub struct CustomEncoder {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The rewrite rules are compiled at instance instantiation to speed execution at run
+    /// time.
+    rules: RuleSet,
+
+    name: String,
+    normalize: bool,
+    keep_first_letter: bool,
+    drop: Vec<char>,
+    dedup: Dedup,
+    codes: Vec<(char, String)>,
+    pad: Option<char>,
+
+    /// The code is truncated to `max_code_len` characters, and padded if the definition gives a
+    /// `pad`.  If `max_code_len` is zero, the code is not truncated.  The default value for
+    /// `max_code_len` is given by the definition.
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is given by the definition.
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for CustomEncoder {
+    /// Return an encoder following the default [`CustomDefinition`], which
+    /// only uppercases the input and removes characters other than "A-Z".
+    fn new() -> CustomEncoder {
+        CustomEncoder::from_definition(&CustomDefinition::default()).unwrap()
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, normalize it, apply the character policy, and uppercase it
+        let return_string = if self.normalize {
+            utils::fold_to_ascii(source_string)
+        } else {
+            String::from(source_string)
+        };
+        let return_string =
+            utils::apply_policy(&return_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Apply the rewrite rules
+        let return_string = self.rules.apply(&return_string);
+
+        // Check if the return string is empty yet
+        if return_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        // Set the first letter aside
+        let first_char = if self.keep_first_letter {
+            return_string.chars().next()
+        } else {
+            None
+        };
+        let return_string = if self.keep_first_letter {
+            utils::strip_first_char(&return_string)
+        } else {
+            return_string
+        };
+
+        // Remove the dropped letters
+        let mut letters: Vec<char> = return_string
+            .chars()
+            .filter(|c| !self.drop.contains(c))
+            .collect();
+
+        // Remove duplicate consecutive letters
+        if self.dedup == Dedup::Letters {
+            letters.dedup();
+        }
+
+        // Replace each letter with its code
+        let mut codes: Vec<String> = letters
+            .iter()
+            .map(
+                |c| match self.codes.iter().find(|(letter, _)| letter == c) {
+                    Some((_, code)) => code.clone(),
+                    None => c.to_string(),
+                },
+            )
+            .collect();
+
+        // Remove duplicate consecutive codes
+        if self.dedup == Dedup::Codes {
+            codes.dedup();
+        }
+
+        // Append the codes to the first letter
+        let mut return_string: String = first_char.into_iter().collect();
+        return_string.push_str(&codes.concat());
+
+        // Pad and truncate to requested length
+        if self.max_code_len > 0 {
+            if let Some(pad) = self.pad {
+                while return_string.chars().count() < self.max_code_len {
+                    return_string.push(pad);
+                }
+            }
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl CustomEncoder {
+    /// Return an encoder following the definition given in TOML in
+    /// `definition`, or [`PhonicsError::InvalidConfiguration`] if it cannot be
+    /// read or is invalid.
+    pub fn from_toml(definition: &str) -> Result<CustomEncoder, PhonicsError> {
+        let definition: CustomDefinition = toml::from_str(definition)
+            .map_err(|e| PhonicsError::InvalidConfiguration(e.to_string()))?;
+
+        CustomEncoder::from_definition(&definition)
+    }
+
+    /// Return an encoder following the definition given in JSON in
+    /// `definition`, or [`PhonicsError::InvalidConfiguration`] if it cannot be
+    /// read or is invalid.
+    pub fn from_json(definition: &str) -> Result<CustomEncoder, PhonicsError> {
+        let definition: CustomDefinition = serde_json::from_str(definition)
+            .map_err(|e| PhonicsError::InvalidConfiguration(e.to_string()))?;
+
+        CustomEncoder::from_definition(&definition)
+    }
+
+    /// Return an encoder following `definition`, or
+    /// [`PhonicsError::InvalidConfiguration`] if it is invalid.
+    pub fn from_definition(definition: &CustomDefinition) -> Result<CustomEncoder, PhonicsError> {
+        let invalid = |message: String| {
+            PhonicsError::InvalidConfiguration(format!(
+                "custom encoder \"{}\": {}",
+                definition.name, message
+            ))
+        };
+
+        // The name tags the codes, as in "name/4:H343"
+        if definition.name.is_empty() || definition.name.contains(&[':', '/'][..]) {
+            return Err(invalid(String::from(
+                "name must not be empty or have a ':' or '/'",
+            )));
+        }
+        if ALGORITHMS.contains(&definition.name.as_str()) {
+            return Err(invalid(String::from(
+                "name must not be that of a built-in algorithm",
+            )));
+        }
+
+        // The input is uppercased, so lowercase letters would never match
+        let fields = [
+            ("alphabet", &definition.alphabet),
+            ("vowels", &definition.vowels),
+            ("drop", &definition.drop),
+        ];
+        for (field, letters) in fields.iter() {
+            if let Some(c) = letters.chars().find(|c| c.is_lowercase()) {
+                return Err(invalid(format!(
+                    "{} has the lowercase letter '{}'",
+                    field, c
+                )));
+            }
+        }
+        if definition.alphabet.is_empty() {
+            return Err(invalid(String::from("alphabet is empty")));
+        }
+
+        let mut rules = Vec::new();
+        for rule in &definition.rules {
+            if rule.pattern.is_empty() {
+                return Err(invalid(String::from("a rule has an empty pattern")));
+            }
+            if rule.pattern.chars().any(|c| c.is_lowercase()) {
+                return Err(invalid(format!(
+                    "the rule pattern \"{}\" has lowercase letters",
+                    rule.pattern
+                )));
+            }
+            let left = parse_context(rule.left.as_deref()).map_err(invalid)?;
+            let right = parse_context(rule.right.as_deref()).map_err(invalid)?;
+            let position = match rule.position.as_deref() {
+                None => Position::Any,
+                Some("initial") => Position::Initial,
+                Some("medial") => Position::Medial,
+                Some("final") => Position::Final,
+                Some(position) => {
+                    return Err(invalid(format!("unknown position \"{}\"", position)))
+                }
+            };
+            rules.push(
+                Rule::new(&rule.pattern, &rule.replacement)
+                    .after(left)
+                    .before(right)
+                    .at(position),
+            );
+        }
+
+        let dedup = match definition.dedup.as_str() {
+            "none" => Dedup::None,
+            "letters" => Dedup::Letters,
+            "codes" => Dedup::Codes,
+            dedup => return Err(invalid(format!("unknown dedup \"{}\"", dedup))),
+        };
+
+        let mut codes: Vec<(char, String)> = Vec::new();
+        for group in &definition.codes {
+            if group.letters.is_empty() {
+                return Err(invalid(format!(
+                    "the code \"{}\" has no letters",
+                    group.code
+                )));
+            }
+            for c in group.letters.chars() {
+                if c.is_lowercase() {
+                    return Err(invalid(format!("codes has the lowercase letter '{}'", c)));
+                }
+                if codes.iter().any(|(letter, _)| *letter == c) {
+                    return Err(invalid(format!(
+                        "the letter '{}' has more than one code",
+                        c
+                    )));
+                }
+                codes.push((c, group.code.clone()));
+            }
+        }
+
+        let pad = match definition.pad.as_deref() {
+            None => None,
+            Some(pad) if pad.chars().count() == 1 => pad.chars().next(),
+            Some(pad) => return Err(invalid(format!("pad \"{}\" is not one character", pad))),
+        };
+        if pad.is_some() && definition.max_code_len == 0 {
+            return Err(invalid(String::from(
+                "padded codes need a max_code_len of at least 1",
+            )));
+        }
+
+        let alphabet = regex::escape(&definition.alphabet);
+
+        Ok(CustomEncoder {
+            special_characters_re: Regex::new(&format!("[^{}]", alphabet)).unwrap(),
+            white_space_re: Regex::new(&format!("[^{}]*", alphabet)).unwrap(),
+            rules: RuleSet::new(&rules, &definition.vowels),
+            name: definition.name.clone(),
+            normalize: definition.normalize,
+            keep_first_letter: definition.keep_first_letter,
+            drop: definition.drop.chars().collect(),
+            dedup,
+            codes,
+            pad,
+            max_code_len: definition.max_code_len,
+            policy: definition.policy,
+        })
+    }
+
+    /// Return the name given by the definition.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Read the context of a rule.
+fn parse_context(context: Option<&str>) -> Result<Context, String> {
+    match context {
+        None => Ok(Context::Any),
+        Some("vowel") => Ok(Context::Vowel),
+        Some("consonant") => Ok(Context::Consonant),
+        Some("boundary") => Ok(Context::Boundary),
+        Some(context) => Err(format!("unknown context \"{}\"", context)),
+    }
+}
+
+impl Configurable for CustomEncoder {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if options.max_code_len == Some(0) && self.pad.is_some() {
+            return Err(PhonicsError::InvalidConfiguration(format!(
+                "custom encoder \"{}\": padded codes need a max_code_len of at least 1",
+                self.name
+            )));
+        }
+
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for CustomEncoder {
+    /// Return the name given by the definition, which tags the codes in
+    /// place of an algorithm name.
+    fn algorithm(&self) -> &str {
+        &self.name
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CustomDefinition, CustomEncoder, PhonicsEncoder};
+
+    #[test]
+    fn test_custom_default_definition() {
+        let e = CustomEncoder::new();
+        let d = CustomDefinition::default();
+
+        assert_eq!(e.name(), d.name);
+        assert_eq!(e.max_code_len, d.max_code_len);
+        assert_eq!(e.policy, d.policy);
+    }
+}
@@ -0,0 +1,194 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::{Captures, Regex};
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Czech letter groups and their West Slavic key symbols.  The symbols are
+/// those of the Polish encoder, with "š" on `X`, "č" on `Q`, and "ř" and "ž"
+/// on `W`.  Vowel length is not significant.
+const CZECH_FOLD: &[(&str, &str)] = &[
+    ("CH", "H"),
+    ("Á", "A"),
+    ("Č", "Q"),
+    ("Ď", "D"),
+    ("É", "E"),
+    ("Ě", "E"),
+    ("Í", "I"),
+    ("Ň", "N"),
+    ("Ó", "O"),
+    ("Ř", "W"),
+    ("Š", "X"),
+    ("Ť", "T"),
+    ("Ú", "U"),
+    ("Ů", "U"),
+    ("Ý", "I"),
+    ("Ž", "W"),
+    ("W", "V"),
+    ("Y", "I"),
+];
+
+/// A Czech phonetic key.
+///
+/// The Czech encoder accepts the Czech alphabet, including the háček
+/// letters "č", "ď", "ě", "ň", "ř", "š", "ť", and "ž" and the long vowels,
+/// and folds letter groups that are pronounced alike.  Vowel length is
+/// ignored, "ř" and "ž" are merged, as are "w" and "v", "ů" and "u", and
+/// "ch" and "h".  Voiced obstruents are devoiced at the end of the word and
+/// before a voiceless obstruent, and "v" and "ř" are devoiced after one.
+/// Finally, repeated symbols are collapsed.
+///
+/// The key is written over the same symbols as the [`Polish`](crate::Polish)
+/// encoder so that, for instance, "Višněvský" and "Wiśniewski" collide.
+/// Characters outside the Czech alphabet are handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Czech, PhonicsEncoder};
+///
+/// let enc = Czech::new();
+/// assert_eq!(enc.encode("Šimánský").unwrap(), "XIMANSKI");
+/// ```
+pub struct Czech {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The progressive devoicing regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    progressive_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Czech::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Czech::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Czech {
+    fn new() -> Czech {
+        Czech {
+            special_characters_re: Regex::new(r"[^A-ZÁČĎÉĚÍŇÓŘŠŤÚŮÝŽ]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZÁČĎÉĚÍŇÓŘŠŤÚŮÝŽ]*").unwrap(),
+            progressive_re: Regex::new(r"([CFHKPQSTX])([VW])").unwrap(),
+            max_code_len: Czech::MAX_CODE_LEN_DEFAULT,
+            policy: Czech::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Fold letter groups onto the shared key symbols
+        let return_string = utils::transliterate(&return_string, CZECH_FOLD);
+
+        // V and Ř following a voiceless obstruent are devoiced
+        let return_string = self
+            .progressive_re
+            .replace_all(&return_string, |caps: &Captures| {
+                let devoiced = if &caps[2] == "V" { "F" } else { "X" };
+                format!("{}{}", &caps[1], devoiced)
+            });
+
+        // Final and regressive devoicing
+        let return_string = utils::devoice_obstruents(&return_string, "BDGVZW", "PTKFSX");
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Czech {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Czech {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Czech {
+    fn algorithm(&self) -> &str {
+        "czech"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Czech, PhonicsEncoder};
+
+    #[test]
+    fn test_czech_default_max_code_len() {
+        let e = Czech::new();
+
+        assert_eq!(e.max_code_len, Czech::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_czech_default_policy() {
+        let e = Czech::new();
+
+        assert_eq!(e.policy, Czech::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,244 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+use crate::UnknownCharacter;
+
+/// The Davidson consonant code.
+///
+/// The Davidson consonant code is a fixed-width key of five characters
+/// built from both the surname and the forename.  The first letter of the
+/// surname is retained and followed by the remaining consonants of the
+/// surname, with "H", "W", and "Y" treated as vowels and removed.  Repeated
+/// letters are collapsed and the surname part is truncated, or padded with
+/// spaces, to four characters.  The first initial of the forename, or a
+/// space if there is none, completes the key.
+///
+/// Because the key needs two names, [`Davidson::encode_name`] takes the
+/// surname and forename separately.  Through [`PhonicsEncoder::encode`],
+/// the name is given as "Surname, Forename", the usual catalog order.  The
+/// Davidson code is only defined for inputs over the standard English
+/// alphabet, _i.e._, "A-Z".  Other characters are handled according to
+/// `policy`.
+///
+/// # References
+///
+/// Leon Davidson. "Retrieval of misspelled names in an airlines passenger
+/// record system." _Communications of the ACM_, 5(3), 169-171, 1962.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Davidson, PhonicsEncoder};
+///
+/// let enc = Davidson::new();
+/// assert_eq!(enc.encode("Howard, James").unwrap(), "HRD J");
+/// assert_eq!(enc.encode_name("Howard", "James").unwrap(), "HRD J");
+/// ```
+pub struct Davidson {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Davidson::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Davidson {
+    fn new() -> Davidson {
+        Davidson {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            policy: Davidson::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // Split "Surname, Forename" at the first comma
+        let comma = source_string.find(',');
+        let (surname, forename) = match comma {
+            Some(comma) => (&source_string[..comma], &source_string[comma + 1..]),
+            None => (source_string, ""),
+        };
+
+        // Test for unprocessable characters, reporting them at their
+        // offsets in the whole name
+        if self.policy == CharacterPolicy::Reject {
+            let surname_start = surname.len() - surname.trim_start().len();
+            let mut found = self.find_unknown(source_string, surname_start, surname.trim());
+            if let Some(comma) = comma {
+                let forename_start = comma + 1 + forename.len() - forename.trim_start().len();
+                found.extend(self.find_unknown(source_string, forename_start, forename.trim()));
+            }
+            if !found.is_empty() {
+                return Err(PhonicsError::UnknownCharactersFound(found));
+            }
+        }
+
+        self.encode_parts(surname.trim(), forename.trim())
+    }
+}
+
+impl Davidson {
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+
+    /// The length of the surname part of the key.
+    pub const SURNAME_CODE_LEN: usize = 4;
+
+    /// Encode a surname given in `surname` and a forename given in
+    /// `forename` and return the result or error.
+    ///
+    /// If `policy` is [`CharacterPolicy::Reject`] and either name has
+    /// characters that cannot be processed, the offsets reported in
+    /// [`PhonicsError::UnknownCharactersFound`] are into the name the
+    /// character was found in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonics::{Davidson, PhonicsEncoder};
+    ///
+    /// let enc = Davidson::new();
+    /// assert_eq!(enc.encode_name("Lee", "").unwrap(), "L    ");
+    /// ```
+    pub fn encode_name(&self, surname: &str, forename: &str) -> Result<String, PhonicsError> {
+        // First, test for unprocessable characters
+        if self.policy == CharacterPolicy::Reject {
+            let mut found = self.find_unknown(surname, 0, surname);
+            found.extend(self.find_unknown(forename, 0, forename));
+            if !found.is_empty() {
+                return Err(PhonicsError::UnknownCharactersFound(found));
+            }
+        }
+
+        self.encode_parts(surname, forename)
+    }
+
+    /// Return the unprocessable characters of `part`, which starts at byte
+    /// `start` of `source`, with their offsets into `source`.
+    fn find_unknown(&self, source: &str, start: usize, part: &str) -> Vec<UnknownCharacter> {
+        let mut found = match utils::check_characters(part, &self.special_characters_re) {
+            Err(PhonicsError::UnknownCharactersFound(found)) => found,
+            _ => return Vec::new(),
+        };
+
+        let char_start = source[..start].chars().count();
+        for c in &mut found {
+            c.byte_offset += start;
+            c.char_offset += char_start;
+        }
+
+        found
+    }
+
+    /// Return the key of the surname `surname` and the forename `forename`,
+    /// after applying `policy` to each.
+    fn encode_parts(&self, surname: &str, forename: &str) -> Result<String, PhonicsError> {
+        // Apply the character policy, uppercase the names, and remove
+        // unprocessable characters
+        let surname = utils::apply_policy(surname, &self.special_characters_re, self.policy)?;
+        let forename = utils::apply_policy(forename, &self.special_characters_re, self.policy)?;
+        let surname = surname.to_uppercase();
+        let forename = forename.to_uppercase();
+        let surname = self.white_space_re.replace_all(&surname, "");
+        let forename = self.white_space_re.replace_all(&forename, "");
+
+        // Check if the surname is empty yet
+        if surname.is_empty() {
+            return Ok(String::new());
+        }
+
+        // First character of key = first character of surname
+        let first_char = surname.chars().next().unwrap();
+        let return_string = utils::strip_first_char(&surname);
+
+        // Delete vowels and Y, W, and H
+        let return_string = utils::transform_characters(&return_string, "AEIOUYWH", "");
+
+        // Remove duplicate consecutive characters, including the first
+        let return_string = format!("{}{}", first_char, return_string);
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Space-pad and truncate to the surname length
+        for _ in 1..Davidson::SURNAME_CODE_LEN {
+            return_string.push(' ');
+        }
+        let mut return_string = return_string.as_str()[0..Davidson::SURNAME_CODE_LEN].to_string();
+
+        // Append the first initial of the forename
+        return_string.push(forename.chars().next().unwrap_or(' '));
+
+        Ok(return_string)
+    }
+}
+
+impl Configurable for Davidson {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if options.max_code_len.is_some() {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "the Davidson consonant code has a fixed length and no max_code_len",
+            )));
+        }
+
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Davidson {
+    fn algorithm(&self) -> &str {
+        "davidson"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Davidson, PhonicsEncoder};
+
+    #[test]
+    fn test_davidson_default_policy() {
+        let e = Davidson::new();
+
+        assert_eq!(e.policy, Davidson::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,240 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Greek letters and letter groups and their ELOT 743 transliterations.
+/// The accents are dropped, and a diaeresis, which separates a vowel from
+/// the one before it, is kept as a ":" until the key is folded.
+const GREEK: &[(&str, &str)] = &[
+    ("Ι\u{0308}", ":I"),
+    ("Υ\u{0308}", ":Y"),
+    ("ΑΥ", "AV"),
+    ("ΑΎ", "AV"),
+    ("ΓΓ", "NG"),
+    ("ΓΚ", "GK"),
+    ("ΓΞ", "NX"),
+    ("ΓΧ", "NCH"),
+    ("ΕΥ", "EV"),
+    ("ΕΎ", "EV"),
+    ("ΗΥ", "IV"),
+    ("ΗΎ", "IV"),
+    ("ΟΥ", "OU"),
+    ("ΟΎ", "OU"),
+    ("Α", "A"),
+    ("Ά", "A"),
+    ("Β", "V"),
+    ("Γ", "G"),
+    ("Δ", "D"),
+    ("Ε", "E"),
+    ("Έ", "E"),
+    ("Ζ", "Z"),
+    ("Η", "I"),
+    ("Ή", "I"),
+    ("Θ", "TH"),
+    ("Ι", "I"),
+    ("Ί", "I"),
+    ("Ϊ", ":I"),
+    ("Κ", "K"),
+    ("Λ", "L"),
+    ("Μ", "M"),
+    ("Ν", "N"),
+    ("Ξ", "X"),
+    ("Ο", "O"),
+    ("Ό", "O"),
+    ("Π", "P"),
+    ("Ρ", "R"),
+    ("Σ", "S"),
+    ("Τ", "T"),
+    ("Υ", "Y"),
+    ("Ύ", "Y"),
+    ("Ϋ", ":Y"),
+    ("Φ", "F"),
+    ("Χ", "CH"),
+    ("Ψ", "PS"),
+    ("Ω", "O"),
+    ("Ώ", "O"),
+    ("Ï", ":I"),
+    ("\u{0301}", ""),
+];
+
+/// Transliterated letter groups and their key symbols.  The vowels and
+/// digraphs that are pronounced alike are merged.
+const LATIN_FOLD: &[(&str, &str)] = &[
+    ("AF", "AV"),
+    ("AI", "E"),
+    ("AU", "AV"),
+    ("CH", "H"),
+    ("EF", "EV"),
+    ("EI", "I"),
+    ("EU", "EV"),
+    ("GG", "G"),
+    ("GK", "G"),
+    ("KH", "H"),
+    ("KS", "X"),
+    ("MP", "B"),
+    ("NG", "G"),
+    ("NT", "D"),
+    ("OI", "I"),
+    ("OU", "U"),
+    ("PH", "F"),
+    ("C", "K"),
+    ("W", "O"),
+    ("Y", "I"),
+    (":", ""),
+];
+
+/// A Greek name key.
+///
+/// The Greek encoder accepts names in Greek script or in Latin
+/// transliteration, either following ELOT 743 or informal, and produces
+/// the same key for both.  Greek script is first transliterated following
+/// ELOT 743, and the transliteration is then folded.  The vowels "η", "ι",
+/// "υ", "ει", and "οι" are all pronounced "i" and are merged, as are "ω"
+/// and "ο", and "αι" and "ε", while "ου" is "u".  The digraphs "μπ", "ντ",
+/// and "γκ" or "γγ" are read as "b", "d", and "g", and "αυ" and "ευ", which
+/// informal spellings write with "u", "f", or "v", are written "av" and
+/// "ev".  In transliterations, "ch" and "kh" are merged with "h", "ph" with
+/// "f", "ks" with "x", and "c" with "k".  A diaeresis keeps a vowel from
+/// combining with the one before it.  Finally, repeated letters are
+/// collapsed.  Thus "Παπαδόπουλος" and "Papadopoulos" encode to
+/// "PAPADOPULOS", and "Αικατερίνη" and "Ekaterini" to "EKATERINI".
+///
+/// Other characters are handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Greek, PhonicsEncoder};
+///
+/// let enc = Greek::new();
+/// assert_eq!(enc.encode("Μπάμπης").unwrap(), enc.encode("Babis").unwrap());
+/// ```
+pub struct Greek {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Greek::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Greek::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Greek {
+    fn new() -> Greek {
+        Greek {
+            special_characters_re: Regex::new(r"[^A-ZÏ\x{0386}-\x{03AB}\x{0301}\x{0308}]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZÏ\x{0386}-\x{03AB}\x{0301}\x{0308}]*").unwrap(),
+            max_code_len: Greek::MAX_CODE_LEN_DEFAULT,
+            policy: Greek::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Transliterate Greek script, then fold the transliteration
+        let return_string = utils::transliterate(&return_string, GREEK);
+        let return_string = utils::transliterate(&return_string, LATIN_FOLD);
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Greek {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Greek {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Greek {
+    fn algorithm(&self) -> &str {
+        "greek"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Greek, PhonicsEncoder};
+
+    #[test]
+    fn test_greek_default_max_code_len() {
+        let e = Greek::new();
+
+        assert_eq!(e.max_code_len, Greek::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_greek_default_policy() {
+        let e = Greek::new();
+
+        assert_eq!(e.policy, Greek::POLICY_DEFAULT);
+    }
+}
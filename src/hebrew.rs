@@ -0,0 +1,264 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::utils::{Position, Rule, RuleSet};
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Hebrew letters, including the final forms and the Yiddish ligatures, and
+/// their consonantal key symbols.  The vowel letters "א", "ו", "י", and "ע"
+/// are dropped, except that a doubled "ו" is read as "v", as are the geresh
+/// and gershayim.
+const HEBREW_FOLD: &[(&str, &str)] = &[
+    ("וו", "B"),
+    ("װ", "B"),
+    ("ױ", ""),
+    ("ײ", ""),
+    ("א", ""),
+    ("ב", "B"),
+    ("ג", "G"),
+    ("ד", "D"),
+    ("ה", "K"),
+    ("ו", ""),
+    ("ז", "Z"),
+    ("ח", "K"),
+    ("ט", "T"),
+    ("י", ""),
+    ("כ", "K"),
+    ("ך", "K"),
+    ("ל", "L"),
+    ("מ", "M"),
+    ("ם", "M"),
+    ("נ", "N"),
+    ("ן", "N"),
+    ("ס", "S"),
+    ("ע", ""),
+    ("פ", "P"),
+    ("ף", "P"),
+    ("צ", "Z"),
+    ("ץ", "Z"),
+    ("ק", "K"),
+    ("ר", "R"),
+    ("ש", "S"),
+    ("ת", "T"),
+    ("׳", ""),
+    ("״", ""),
+];
+
+/// The romanized folding rules, in the order they are applied.
+const LATIN_RULES: &[Rule] = &[
+    // A final "ah" or "eh" is the silent final "ה"
+    Rule::new("AH", "").at(Position::Final),
+    Rule::new("EH", "").at(Position::Final),
+    // Consonants
+    Rule::new("SCH", "S"),
+    Rule::new("SH", "S"),
+    Rule::new("SZ", "S"),
+    Rule::new("TZ", "Z"),
+    Rule::new("TS", "Z"),
+    Rule::new("CZ", "Z"),
+    Rule::new("PH", "P"),
+    Rule::new("F", "P"),
+    Rule::new("TH", "T"),
+    Rule::new("CH", "K"),
+    Rule::new("KH", "K"),
+    Rule::new("CK", "K"),
+    Rule::new("C", "K"),
+    Rule::new("H", "K"),
+    Rule::new("Q", "K"),
+    Rule::new("V", "B"),
+    Rule::new("W", "B"),
+    // Vowels and glides
+    Rule::new("A", ""),
+    Rule::new("E", ""),
+    Rule::new("I", ""),
+    Rule::new("J", ""),
+    Rule::new("O", ""),
+    Rule::new("U", ""),
+    Rule::new("Y", ""),
+    Rule::new("'", ""),
+];
+
+/// A Hebrew and Yiddish name key.
+///
+/// The Hebrew encoder produces a consonantal key for names given in Hebrew
+/// script or in modern Hebrew, Yiddish, or German and English
+/// transliteration.  In Hebrew script, the niqqud and cantillation marks
+/// are ignored, the final letter forms are read as the ordinary letters,
+/// and a final "ה" is silent.  A "ו" is read as "v" when it is doubled or
+/// begins a word, and is otherwise a vowel, as are "א", "י", and "ע".
+///
+/// Because the niqqud are usually not written, and transliterations differ
+/// in how they render the letters they distinguish, the key merges "b",
+/// "v", and "w"; "p", "f", and "ph"; "k", "c", "q", "ch", "kh", and "h";
+/// "s", "sh", and "sch"; and "z", "tz", and "ts".  All vowels, and the
+/// glides "y" and "j", are dropped, as is a final "ah" or "eh".  Thus
+/// "Chaim", "Haim", "Chayim", and "חַיִּים" encode to "KM", and "Yosef",
+/// "Joseph", and "יוסף" to "SP".
+///
+/// Spaces and hyphens, including the maqaf, separate words.  Other unknown
+/// characters are handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Hebrew, PhonicsEncoder};
+///
+/// let enc = Hebrew::new();
+/// assert_eq!(enc.encode("Cohen").unwrap(), enc.encode("כֹּהֵן").unwrap());
+/// ```
+pub struct Hebrew {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The Hebrew script detection regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    hebrew_script_re: Regex,
+
+    /// The niqqud and cantillation removal regular expression is precompiled at instance
+    /// instantiation to speed execution at run time.
+    niqqud_re: Regex,
+
+    /// The romanized rewrite rules are compiled at instance instantiation to speed execution
+    /// at run time.
+    latin_rules: RuleSet,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Hebrew::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Hebrew::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Hebrew {
+    fn new() -> Hebrew {
+        Hebrew {
+            special_characters_re: Regex::new(r"[^A-Z\x{0591}-\x{05F4}' \-]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z\x{0591}-\x{05F4}' \-]*").unwrap(),
+            hebrew_script_re: Regex::new(r"[\x{05D0}-\x{05F2}]").unwrap(),
+            niqqud_re: Regex::new(r"[\x{0591}-\x{05BD}\x{05BF}-\x{05C7}]").unwrap(),
+            latin_rules: RuleSet::new(LATIN_RULES, "AEIOU"),
+            max_code_len: Hebrew::MAX_CODE_LEN_DEFAULT,
+            policy: Hebrew::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Fold each word, in Hebrew script or romanized
+        let return_string: String = return_string
+            .split(&[' ', '-', '\u{05BE}'][..])
+            .map(|word| {
+                if self.hebrew_script_re.is_match(word) {
+                    let word = self.niqqud_re.replace_all(word, "");
+                    let word = word.strip_suffix('ה').unwrap_or(&word);
+                    match word.strip_prefix('ו') {
+                        Some(rest) => format!("B{}", utils::transliterate(rest, HEBREW_FOLD)),
+                        None => utils::transliterate(word, HEBREW_FOLD),
+                    }
+                } else {
+                    self.latin_rules.apply(word)
+                }
+            })
+            .collect();
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Hebrew {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Hebrew {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Hebrew {
+    fn algorithm(&self) -> &str {
+        "hebrew"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Hebrew, PhonicsEncoder};
+
+    #[test]
+    fn test_hebrew_default_max_code_len() {
+        let e = Hebrew::new();
+
+        assert_eq!(e.max_code_len, Hebrew::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_hebrew_default_policy() {
+        let e = Hebrew::new();
+
+        assert_eq!(e.policy, Hebrew::POLICY_DEFAULT);
+    }
+}
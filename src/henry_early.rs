@@ -0,0 +1,318 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// French accented letters and ligatures and their unaccented forms.
+const ACCENTS: &[(&str, &str)] = &[
+    ("À", "A"),
+    ("Â", "A"),
+    ("Ä", "A"),
+    ("Æ", "AE"),
+    ("Ç", "S"),
+    ("È", "E"),
+    ("É", "E"),
+    ("Ê", "E"),
+    ("Ë", "E"),
+    ("Î", "I"),
+    ("Ï", "I"),
+    ("Ô", "O"),
+    ("Ö", "O"),
+    ("Œ", "OE"),
+    ("Ù", "U"),
+    ("Û", "U"),
+    ("Ü", "U"),
+    ("Ÿ", "Y"),
+];
+
+/// Initial diphthongs and the single vowels they are pronounced as.
+const DIPHTHONGS: &[(&str, &str)] = &[
+    ("AI", "E"),
+    ("AY", "E"),
+    ("EI", "E"),
+    ("AU", "O"),
+    ("OI", "O"),
+    ("OU", "O"),
+    ("EU", "U"),
+];
+
+/// Return `true` if `c` is a vowel, including "Y".
+fn is_vowel(c: char) -> bool {
+    "AEIOUY".contains(c)
+}
+
+/// Return `true` if `c` is a consonant.
+fn is_consonant(c: char) -> bool {
+    c.is_ascii_uppercase() && !is_vowel(c)
+}
+
+/// The early version of the Henry code.
+///
+/// The Henry code was designed for French surnames.  A leading vowel is
+/// first normalized by the letters that follow it, so an initial diphthong
+/// such as "AU" becomes the single vowel it is pronounced as.  The letters
+/// are then coded from left to right: doubled consonants are reduced, the
+/// letters "C", "G", "P", "Q", and "S" are coded by the letter following
+/// them, "H" after a consonant is silent, and the first of two consonants
+/// other than "L" and "R" is dropped, as is a nasal "M" or "N".  Silent
+/// endings, such as "-AULT", "-ER", and a final "D", "S", or "T" after a
+/// vowel, are then removed.  Finally, vowels after the first letter are
+/// removed and the code is truncated.
+///
+/// Accented French letters are folded onto their unaccented forms, with
+/// "Ç" coded as "S".  Other characters are handled according to `policy`.
+///
+/// # References
+///
+/// Louis Henry. "Construction d'un code phonétique pour les noms de
+/// famille." In _Les Noms de Famille en France_, 1976.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{HenryEarly, PhonicsEncoder};
+///
+/// let enc = HenryEarly::new();
+/// assert_eq!(enc.encode("Marchand").unwrap(), "MRC");
+/// ```
+pub struct HenryEarly {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The Henry code has a standard maximum length.  However, it may be reasonable to use
+    /// either a shorter or longer length, depending on the application.  If `max_code_len` is
+    /// zero, the code is not truncated.  The default value for `max_code_len` is in
+    /// [`HenryEarly::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`HenryEarly::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for HenryEarly {
+    fn new() -> HenryEarly {
+        HenryEarly {
+            special_characters_re: Regex::new(r"[^A-ZÀÂÄÆÇÈÉÊËÎÏÔÖŒÙÛÜŸ]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZÀÂÄÆÇÈÉÊËÎÏÔÖŒÙÛÜŸ]*").unwrap(),
+            max_code_len: HenryEarly::MAX_CODE_LEN_DEFAULT,
+            policy: HenryEarly::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+        let return_string = utils::transliterate(&return_string, ACCENTS);
+
+        // Check if the return string is empty yet
+        if return_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        // Rule I: normalize an initial vowel by the letters following it
+        let mut word: Vec<char> = return_string.chars().collect();
+        let second = word.get(1).copied().unwrap_or(' ');
+        let third = word.get(2).copied().unwrap_or(' ');
+        if is_vowel(word[0]) {
+            if (is_consonant(second) && second != 'M' && second != 'N' && is_consonant(third))
+                || (is_consonant(second) && !is_consonant(third))
+            {
+                if word[0] == 'Y' {
+                    word[0] = 'I';
+                }
+            } else if (second == 'M' || second == 'N') && is_consonant(third) {
+                match word[0] {
+                    'E' => word[0] = 'A',
+                    'I' | 'U' | 'Y' => word[0] = 'E',
+                    _ => {}
+                }
+            } else if let Some((_, vowel)) = DIPHTHONGS
+                .iter()
+                .find(|(diphthong, _)| return_string.starts_with(diphthong))
+            {
+                word.splice(0..2, vowel.chars());
+            } else if is_vowel(second) && word[0] == 'Y' {
+                word[0] = 'I';
+            }
+        }
+
+        // Rule II: code the letters from left to right
+        let mut code = String::new();
+        let mut skip = 0;
+        for (pos, &c) in word.iter().enumerate() {
+            let prev = if pos > 0 { word[pos - 1] } else { ' ' };
+            let next = word.get(pos + 1).copied().unwrap_or(' ');
+            let after = word.get(pos + 2).copied().unwrap_or(' ');
+            let rest: String = word[pos..].iter().collect();
+
+            if skip > 0 {
+                skip -= 1;
+            } else if is_vowel(c) {
+                code.push(c);
+            } else if c == next {
+                code.push(c);
+                skip = 1;
+            } else if rest.starts_with("CQ") || rest.starts_with("DT") || rest.starts_with("SC") {
+                continue;
+            } else if c == 'C' {
+                if "AOULR".contains(next) {
+                    code.push('K');
+                } else if "EIY".contains(next) {
+                    code.push('S');
+                } else if next == 'H' && !is_vowel(after) {
+                    code.push('K');
+                } else {
+                    code.push('C');
+                }
+            } else if c == 'G' {
+                if "EIY".contains(next) {
+                    code.push('J');
+                } else if next == 'N' {
+                    code.push('N');
+                    skip = 1;
+                } else {
+                    code.push('G');
+                }
+            } else if c == 'P' {
+                code.push(if next == 'H' { 'F' } else { 'P' });
+            } else if c == 'Q' {
+                code.push('K');
+            } else if c == 'S' && pos == 0 && rest.starts_with("SAINTE") {
+                code.push('X');
+                skip = 5;
+            } else if c == 'S' && pos == 0 && rest.starts_with("SAINT") {
+                code.push('X');
+                skip = 4;
+            } else if c == 'S' && pos == 0 && rest.starts_with("STE") {
+                code.push('X');
+                skip = 2;
+            } else if c == 'S' && pos == 0 && rest.starts_with("ST") {
+                code.push('X');
+                skip = 1;
+            } else {
+                // Silent consonants are not coded
+                let silent = (c == 'S' && is_consonant(next))
+                    || (c == 'H' && is_consonant(prev))
+                    || (c != 'L' && c != 'R' && is_consonant(next) && next != 'L' && next != 'R')
+                    || (c == 'L' && (next == 'M' || next == 'N'))
+                    || ((c == 'M' || c == 'N') && is_vowel(prev) && is_consonant(next));
+                if !silent {
+                    code.push(c);
+                }
+            }
+        }
+
+        // Rule III: remove silent endings
+        let code_chars: Vec<char> = code.chars().collect();
+        let len = code_chars.len();
+        let penultimate = if len > 1 { code_chars[len - 2] } else { ' ' };
+        let last = code_chars.last().copied().unwrap_or(' ');
+        if code.ends_with("AULT") || code.ends_with("EULT") || code.ends_with("OULT") {
+            code.truncate(code.len() - 2);
+        } else if (penultimate == 'R' && is_consonant(last))
+            || (is_vowel(penultimate) && "DMNST".contains(last))
+            || code.ends_with("ER")
+        {
+            code.pop();
+        }
+
+        // Delete vowels after the first character
+        let first_char: String = code.chars().take(1).collect();
+        let return_string = utils::strip_first_char(&code);
+        let return_string = utils::transform_characters(&return_string, "AEIOUY", "");
+        let mut return_string = format!("{}{}", first_char, return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl HenryEarly {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 3;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for HenryEarly {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for HenryEarly {
+    fn algorithm(&self) -> &str {
+        "henry_early"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HenryEarly, PhonicsEncoder};
+
+    #[test]
+    fn test_henry_early_default_max_code_len() {
+        let e = HenryEarly::new();
+
+        assert_eq!(e.max_code_len, HenryEarly::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_henry_early_default_policy() {
+        let e = HenryEarly::new();
+
+        assert_eq!(e.policy, HenryEarly::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,192 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Romanized letter groups and their replacements, longest first.  The
+/// aspirated consonants lose their "H", and letters that transliterations
+/// use interchangeably are merged.
+const INDIAN_FOLD: &[(&str, &str)] = &[
+    ("CHH", "C"),
+    ("KSH", "KS"),
+    ("AA", "A"),
+    ("BH", "B"),
+    ("CH", "C"),
+    ("CK", "K"),
+    ("DH", "D"),
+    ("EE", "I"),
+    ("GH", "G"),
+    ("JH", "J"),
+    ("KH", "K"),
+    ("OO", "U"),
+    ("OW", "O"),
+    ("PH", "F"),
+    ("SH", "S"),
+    ("TH", "T"),
+    ("C", "K"),
+    ("Q", "K"),
+    ("W", "V"),
+    ("X", "KS"),
+    ("Z", "J"),
+];
+
+/// An Indian name key.
+///
+/// The Indian encoder adapts the [`ParmarKumbharana`](crate::ParmarKumbharana)
+/// procedure to personal names romanized from Hindi, Gujarati, and other
+/// Indian languages.  Parmar and Kumbharana's rules for the silent and soft
+/// letters of English are replaced with rules for the common variations in
+/// transliteration.  The aspirated consonants "BH", "CHH", "DH", "GH", "JH",
+/// "KH", "PH", and "TH" lose their aspiration, "SH" and "S" are merged, as
+/// are "KSH", "KS", and "X", "W" and "V", "Z" and "J", and "C", "Q", and
+/// "K", while "CH" is kept apart.  Repeated letters are then collapsed, and
+/// the vowels and "Y" are removed from all but the first letter, so that the
+/// final inherent vowel, which is written in some spellings and not in
+/// others, is ignored.  Thus "Shrivastava" and "Srivastav", "Bhatt" and
+/// "Bhat", and "Laxmi" and "Lakshmi" collide.
+///
+/// The Indian encoder is only defined for inputs over the standard English
+/// alphabet, _i.e._, "A-Z".  Other characters are handled according to
+/// `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Indian, PhonicsEncoder};
+///
+/// let enc = Indian::new();
+/// assert_eq!(enc.encode("Laxmi").unwrap(), enc.encode("Lakshmi").unwrap());
+/// ```
+pub struct Indian {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Indian::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Indian::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Indian {
+    fn new() -> Indian {
+        Indian {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            max_code_len: Indian::MAX_CODE_LEN_DEFAULT,
+            policy: Indian::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Fold the transliteration variants, then remove duplicate
+        // consecutive characters
+        let return_string = utils::transliterate(&return_string, INDIAN_FOLD);
+        let return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Remove the vowels after the first letter
+        let mut return_string: String = return_string
+            .char_indices()
+            .filter(|(i, c)| *i == 0 || !"AEIOUY".contains(*c))
+            .map(|(_, c)| c)
+            .collect();
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Indian {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Indian {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Indian {
+    fn algorithm(&self) -> &str {
+        "indian"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Indian, PhonicsEncoder};
+
+    #[test]
+    fn test_indian_default_max_code_len() {
+        let e = Indian::new();
+
+        assert_eq!(e.max_code_len, Indian::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_indian_default_policy() {
+        let e = Indian::new();
+
+        assert_eq!(e.policy, Indian::POLICY_DEFAULT);
+    }
+}
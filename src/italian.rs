@@ -0,0 +1,214 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Accented vowels and their unaccented forms.
+const ACCENTS: &[(&str, &str)] = &[
+    ("À", "A"),
+    ("È", "E"),
+    ("É", "E"),
+    ("Ì", "I"),
+    ("Í", "I"),
+    ("Ò", "O"),
+    ("Ó", "O"),
+    ("Ù", "U"),
+    ("Ú", "U"),
+];
+
+/// Italian letter groups and their key symbols.  The key uses `K` for the
+/// hard "c", `C` for the soft "c" before "e" and "i", `J` for the soft "g"
+/// and for "j", `X` for "sc" before "e" and "i", `L` for "gli", and `N` for
+/// "gn".  A silent "i" marking a soft consonant is dropped.
+const ITALIAN_FOLD: &[(&str, &str)] = &[
+    ("SCHE", "SKE"),
+    ("SCHI", "SKI"),
+    ("SCIA", "XA"),
+    ("SCIO", "XO"),
+    ("SCIU", "XU"),
+    ("SCE", "XE"),
+    ("SCI", "XI"),
+    ("SC", "SK"),
+    ("CHE", "KE"),
+    ("CHI", "KI"),
+    ("CIA", "CA"),
+    ("CIO", "CO"),
+    ("CIU", "CU"),
+    ("CE", "CE"),
+    ("CI", "CI"),
+    ("C", "K"),
+    ("GHE", "GE"),
+    ("GHI", "GI"),
+    ("GLIA", "LA"),
+    ("GLIE", "LE"),
+    ("GLIO", "LO"),
+    ("GLIU", "LU"),
+    ("GLI", "LI"),
+    ("GIA", "JA"),
+    ("GIE", "JE"),
+    ("GIO", "JO"),
+    ("GIU", "JU"),
+    ("GE", "JE"),
+    ("GI", "JI"),
+    ("GN", "N"),
+    ("H", ""),
+    ("Q", "K"),
+    ("X", "KS"),
+    ("Y", "I"),
+    ("W", "V"),
+];
+
+/// An Italian phonetic key.
+///
+/// The Italian encoder accepts the Italian alphabet with accented vowels,
+/// such as "à", "è", and "ò", which are folded onto their unaccented forms.
+/// Doubled consonants are reduced to one, so "Scotti" and "Scoti" collide.
+/// The letters "c" and "g" are coded by the vowel that follows them: before
+/// "e" and "i" they are soft, elsewhere hard, and "ch" and "gh" are always
+/// hard.  The soft "g" is merged with "j", so "Gianni" and "Janni" collide.
+/// The groups "gli", "gn", and "sc" are coded as the single sounds they
+/// represent, an "i" that only marks a soft consonant is dropped, and the
+/// silent "h" is removed.
+///
+/// Characters outside the Italian alphabet are handled according to
+/// `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Italian, PhonicsEncoder};
+///
+/// let enc = Italian::new();
+/// assert_eq!(enc.encode("Ciccone").unwrap(), "CIKONE");
+/// ```
+pub struct Italian {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Italian::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Italian::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Italian {
+    fn new() -> Italian {
+        Italian {
+            special_characters_re: Regex::new(r"[^A-ZÀÈÉÌÍÒÓÙÚ]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZÀÈÉÌÍÒÓÙÚ]*").unwrap(),
+            max_code_len: Italian::MAX_CODE_LEN_DEFAULT,
+            policy: Italian::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Remove accents and reduce doubled consonants
+        let return_string = utils::transliterate(&return_string, ACCENTS);
+        let return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Fold letter groups onto the key symbols
+        let return_string = utils::transliterate(&return_string, ITALIAN_FOLD);
+
+        // Remove duplicates created by folding, such as "cq"
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Italian {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Italian {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Italian {
+    fn algorithm(&self) -> &str {
+        "italian"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Italian, PhonicsEncoder};
+
+    #[test]
+    fn test_italian_default_max_code_len() {
+        let e = Italian::new();
+
+        assert_eq!(e.max_code_len, Italian::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_italian_default_policy() {
+        let e = Italian::new();
+
+        assert_eq!(e.policy, Italian::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,382 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Vowels with macrons and circumflexes, which mark long vowels in
+/// romanized Japanese, and their plain forms.
+const LONG_VOWELS: &[(&str, &str)] = &[
+    ("Ā", "A"),
+    ("Â", "A"),
+    ("Ē", "E"),
+    ("Ê", "E"),
+    ("Ī", "I"),
+    ("Î", "I"),
+    ("Ō", "O"),
+    ("Ô", "O"),
+    ("Ū", "U"),
+    ("Û", "U"),
+];
+
+/// Kunrei-shiki and Nihon-shiki syllables and their Hepburn spellings.  The
+/// Hepburn "CHU" and "SHU" are listed as themselves so that their "HU" is
+/// not read as Kunrei-shiki.
+const KUNREI: &[(&str, &str)] = &[
+    ("CHU", "CHU"),
+    ("DYA", "JA"),
+    ("DYO", "JO"),
+    ("DYU", "JU"),
+    ("JYA", "JA"),
+    ("JYO", "JO"),
+    ("JYU", "JU"),
+    ("SHU", "SHU"),
+    ("SYA", "SHA"),
+    ("SYO", "SHO"),
+    ("SYU", "SHU"),
+    ("TYA", "CHA"),
+    ("TYO", "CHO"),
+    ("TYU", "CHU"),
+    ("ZYA", "JA"),
+    ("ZYO", "JO"),
+    ("ZYU", "JU"),
+    ("DI", "JI"),
+    ("DU", "ZU"),
+    ("HU", "FU"),
+    ("SI", "SHI"),
+    ("TI", "CHI"),
+    ("TU", "TSU"),
+    ("ZI", "JI"),
+];
+
+/// Katakana and their Hepburn spellings, with the contracted syllables
+/// first.  The small "ッ" and the long vowel mark "ー" only lengthen a sound
+/// and are dropped.
+const KATAKANA: &[(&str, &str)] = &[
+    ("キャ", "KYA"),
+    ("キュ", "KYU"),
+    ("キョ", "KYO"),
+    ("ギャ", "GYA"),
+    ("ギュ", "GYU"),
+    ("ギョ", "GYO"),
+    ("シャ", "SHA"),
+    ("シュ", "SHU"),
+    ("ショ", "SHO"),
+    ("シェ", "SHE"),
+    ("ジャ", "JA"),
+    ("ジュ", "JU"),
+    ("ジョ", "JO"),
+    ("ジェ", "JE"),
+    ("チャ", "CHA"),
+    ("チュ", "CHU"),
+    ("チョ", "CHO"),
+    ("チェ", "CHE"),
+    ("ヂャ", "JA"),
+    ("ヂュ", "JU"),
+    ("ヂョ", "JO"),
+    ("ニャ", "NYA"),
+    ("ニュ", "NYU"),
+    ("ニョ", "NYO"),
+    ("ヒャ", "HYA"),
+    ("ヒュ", "HYU"),
+    ("ヒョ", "HYO"),
+    ("ビャ", "BYA"),
+    ("ビュ", "BYU"),
+    ("ビョ", "BYO"),
+    ("ピャ", "PYA"),
+    ("ピュ", "PYU"),
+    ("ピョ", "PYO"),
+    ("ミャ", "MYA"),
+    ("ミュ", "MYU"),
+    ("ミョ", "MYO"),
+    ("リャ", "RYA"),
+    ("リュ", "RYU"),
+    ("リョ", "RYO"),
+    ("ファ", "FA"),
+    ("フィ", "FI"),
+    ("フェ", "FE"),
+    ("フォ", "FO"),
+    ("ティ", "TI"),
+    ("ディ", "DI"),
+    ("ウィ", "WI"),
+    ("ウェ", "WE"),
+    ("ウォ", "WO"),
+    ("ヴァ", "VA"),
+    ("ヴィ", "VI"),
+    ("ヴェ", "VE"),
+    ("ヴォ", "VO"),
+    ("ア", "A"),
+    ("イ", "I"),
+    ("ウ", "U"),
+    ("エ", "E"),
+    ("オ", "O"),
+    ("カ", "KA"),
+    ("キ", "KI"),
+    ("ク", "KU"),
+    ("ケ", "KE"),
+    ("コ", "KO"),
+    ("ガ", "GA"),
+    ("ギ", "GI"),
+    ("グ", "GU"),
+    ("ゲ", "GE"),
+    ("ゴ", "GO"),
+    ("サ", "SA"),
+    ("シ", "SHI"),
+    ("ス", "SU"),
+    ("セ", "SE"),
+    ("ソ", "SO"),
+    ("ザ", "ZA"),
+    ("ジ", "JI"),
+    ("ズ", "ZU"),
+    ("ゼ", "ZE"),
+    ("ゾ", "ZO"),
+    ("タ", "TA"),
+    ("チ", "CHI"),
+    ("ツ", "TSU"),
+    ("テ", "TE"),
+    ("ト", "TO"),
+    ("ダ", "DA"),
+    ("ヂ", "JI"),
+    ("ヅ", "ZU"),
+    ("デ", "DE"),
+    ("ド", "DO"),
+    ("ナ", "NA"),
+    ("ニ", "NI"),
+    ("ヌ", "NU"),
+    ("ネ", "NE"),
+    ("ノ", "NO"),
+    ("ハ", "HA"),
+    ("ヒ", "HI"),
+    ("フ", "FU"),
+    ("ヘ", "HE"),
+    ("ホ", "HO"),
+    ("バ", "BA"),
+    ("ビ", "BI"),
+    ("ブ", "BU"),
+    ("ベ", "BE"),
+    ("ボ", "BO"),
+    ("パ", "PA"),
+    ("ピ", "PI"),
+    ("プ", "PU"),
+    ("ペ", "PE"),
+    ("ポ", "PO"),
+    ("マ", "MA"),
+    ("ミ", "MI"),
+    ("ム", "MU"),
+    ("メ", "ME"),
+    ("モ", "MO"),
+    ("ヤ", "YA"),
+    ("ユ", "YU"),
+    ("ヨ", "YO"),
+    ("ラ", "RA"),
+    ("リ", "RI"),
+    ("ル", "RU"),
+    ("レ", "RE"),
+    ("ロ", "RO"),
+    ("ワ", "WA"),
+    ("ヰ", "I"),
+    ("ヱ", "E"),
+    ("ヲ", "O"),
+    ("ン", "N"),
+    ("ヴ", "VU"),
+    ("ァ", "A"),
+    ("ィ", "I"),
+    ("ゥ", "U"),
+    ("ェ", "E"),
+    ("ォ", "O"),
+    ("ャ", "YA"),
+    ("ュ", "YU"),
+    ("ョ", "YO"),
+    ("ヮ", "WA"),
+    ("ヵ", "KA"),
+    ("ヶ", "KE"),
+    ("ッ", ""),
+    ("ー", ""),
+];
+
+/// A Japanese name key.
+///
+/// The Japanese encoder accepts names written in hiragana, katakana, or
+/// romaji and produces a key in simplified Hepburn romanization.  Hiragana
+/// is read as katakana, kana are romanized, and Kunrei-shiki and
+/// Nihon-shiki spellings, such as "Tukuda" and "Husimi", are rewritten in
+/// Hepburn, as "Tsukuda" and "Fushimi".
+///
+/// Long vowels are written in many ways, so vowels with macrons or
+/// circumflexes are folded to plain vowels, "ou" and "oh" before a
+/// consonant are read as a long "o", and repeated letters, including
+/// doubled vowels and doubled consonants, are collapsed.  Thus "Ohno",
+/// "Ono", "Oono", "Ōno", and "オオノ" all encode to "ONO".  An "m" before
+/// "b", "m", or "p" is read as the syllabic "n", so "Homma" and "Honma"
+/// collide.
+///
+/// Other characters, such as the apostrophe separating a syllabic "n"
+/// from a following vowel, are handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Japanese, PhonicsEncoder};
+///
+/// let enc = Japanese::new();
+/// assert_eq!(enc.encode("Ohno").unwrap(), enc.encode("おおの").unwrap());
+/// ```
+pub struct Japanese {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The long "o" regular expression is precompiled at instance instantiation to speed
+    /// execution at run time.
+    long_o_re: Regex,
+
+    /// The syllabic "n" regular expression is precompiled at instance instantiation to speed
+    /// execution at run time.
+    syllabic_n_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Japanese::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Japanese::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Japanese {
+    fn new() -> Japanese {
+        Japanese {
+            special_characters_re: Regex::new(
+                r"[^A-ZĀÂĒÊĪÎŌÔŪÛ\x{3041}-\x{3096}\x{30A1}-\x{30FA}\x{30FC}]",
+            )
+            .unwrap(),
+            white_space_re: Regex::new(
+                r"[^A-ZĀÂĒÊĪÎŌÔŪÛ\x{3041}-\x{3096}\x{30A1}-\x{30FA}\x{30FC}]*",
+            )
+            .unwrap(),
+            long_o_re: Regex::new(r"O[UH]([^AEIOUY]|$)").unwrap(),
+            syllabic_n_re: Regex::new(r"M([BMP])").unwrap(),
+            max_code_len: Japanese::MAX_CODE_LEN_DEFAULT,
+            policy: Japanese::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Read hiragana as the matching katakana
+        let return_string: String = return_string
+            .chars()
+            .map(|c| match c {
+                '\u{3041}'..='\u{3096}' => std::char::from_u32(c as u32 + 0x60).unwrap(),
+                _ => c,
+            })
+            .collect();
+
+        // Fold the long vowel marks and the Kunrei-shiki spellings, then
+        // romanize the kana
+        let return_string = utils::transliterate(&return_string, LONG_VOWELS);
+        let return_string = utils::transliterate(&return_string, KUNREI);
+        let return_string = utils::transliterate(&return_string, KATAKANA);
+
+        // OU and OH before a consonant are a long O, and M before a labial
+        // is the syllabic N
+        let return_string = self.long_o_re.replace_all(&return_string, "O${1}");
+        let return_string = self.syllabic_n_re.replace_all(&return_string, "N${1}");
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Japanese {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Japanese {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Japanese {
+    fn algorithm(&self) -> &str {
+        "japanese"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Japanese, PhonicsEncoder};
+
+    #[test]
+    fn test_japanese_default_max_code_len() {
+        let e = Japanese::new();
+
+        assert_eq!(e.max_code_len, Japanese::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_japanese_default_policy() {
+        let e = Japanese::new();
+
+        assert_eq!(e.policy, Japanese::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,278 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::utils::{Context, Position, Rule, RuleSet};
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// The Revised Romanization of the 19 initial consonants of a Hangul
+/// syllable, in Unicode order.
+const INITIALS: [&str; 19] = [
+    "G", "KK", "N", "D", "TT", "R", "M", "B", "PP", "S", "SS", "", "J", "JJ", "CH", "K", "T", "P",
+    "H",
+];
+
+/// The Revised Romanization of the 21 vowels of a Hangul syllable, in
+/// Unicode order.
+const VOWELS: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WO", "WE",
+    "WI", "YU", "EU", "UI", "I",
+];
+
+/// The Revised Romanization of the 28 final consonants of a Hangul syllable,
+/// including none, in Unicode order.
+const FINALS: [&str; 28] = [
+    "", "K", "K", "K", "N", "N", "N", "T", "L", "K", "M", "L", "L", "L", "P", "L", "M", "P", "P",
+    "T", "T", "NG", "T", "T", "K", "T", "P", "T",
+];
+
+/// McCune-Reischauer vowels with breves and aspiration marks, and their
+/// replacements.
+const MCCUNE_REISCHAUER: &[(&str, &str)] = &[("Ŏ", "EO"), ("Ŭ", "EU"), ("'", ""), ("’", "")];
+
+/// The romanization folding rules, in the order they are applied.  The
+/// glides "w" and "y" are read as vowels.
+const RULES: &[Rule] = &[
+    // Vowels
+    Rule::new("EE", "I"),
+    Rule::new("OO", "U"),
+    Rule::new("EO", "U"),
+    Rule::new("EU", "U"),
+    Rule::new("AE", "E"),
+    Rule::new("OI", "OE"),
+    Rule::new("WE", "OE"),
+    Rule::new("YI", "I"),
+    // An initial liquid or nasal is silent before "i" and "y", and an
+    // initial liquid is otherwise read as "n"
+    Rule::new("LHI", "I").at(Position::Initial),
+    Rule::new("LHY", "Y").at(Position::Initial),
+    Rule::new("LI", "I").at(Position::Initial),
+    Rule::new("LY", "Y").at(Position::Initial),
+    Rule::new("NHI", "I").at(Position::Initial),
+    Rule::new("NHY", "Y").at(Position::Initial),
+    Rule::new("NI", "I").at(Position::Initial),
+    Rule::new("NY", "Y").at(Position::Initial),
+    Rule::new("RHI", "I").at(Position::Initial),
+    Rule::new("RHY", "Y").at(Position::Initial),
+    Rule::new("RI", "I").at(Position::Initial),
+    Rule::new("RY", "Y").at(Position::Initial),
+    Rule::new("LH", "N").at(Position::Initial),
+    Rule::new("L", "N").at(Position::Initial),
+    Rule::new("RH", "N").at(Position::Initial),
+    Rule::new("R", "N").at(Position::Initial),
+    // An "r" or "h" after a vowel and before a consonant only marks the vowel
+    Rule::new("R", "")
+        .after(Context::Vowel)
+        .before(Context::Consonant),
+    Rule::new("H", "")
+        .after(Context::Vowel)
+        .before(Context::Consonant),
+    Rule::new("H", "")
+        .after(Context::Vowel)
+        .before(Context::Boundary),
+    // Consonants
+    Rule::new("TCH", "C"),
+    Rule::new("JJ", "C"),
+    Rule::new("CHH", "C"),
+    Rule::new("CH", "C"),
+    Rule::new("J", "C"),
+    Rule::new("SH", "S"),
+    Rule::new("SS", "S"),
+    Rule::new("KK", "K"),
+    Rule::new("KH", "K"),
+    Rule::new("G", "K").after(Context::Except('N')),
+    Rule::new("TT", "T"),
+    Rule::new("TH", "T"),
+    Rule::new("D", "T"),
+    Rule::new("PP", "P"),
+    Rule::new("PH", "P"),
+    Rule::new("B", "P"),
+    Rule::new("R", "L"),
+];
+
+/// A Korean name key.
+///
+/// The Korean encoder accepts names written in Hangul, in the Revised
+/// Romanization, in McCune-Reischauer, and in the common informal
+/// spellings, and folds them to a shared key.  Hangul syllables are
+/// decomposed into their initial, vowel, and final, and are written in the
+/// Revised Romanization.  McCune-Reischauer breves and aspiration marks are
+/// removed.
+///
+/// The romanizations differ mostly in whether they mark aspiration and
+/// voicing, so "g" and "k", "d" and "t", "b" and "p", and "j" and "ch" are
+/// merged, as are the tense consonants.  The vowels "eo", "ŏ", "eu", "ŭ",
+/// "oo", and "u" are merged, as are "ee" and "i", and "ae" and "e".  An
+/// initial "l", "r", or "n" before "i" or "y" is silent, and an initial "l"
+/// or "r" is otherwise read as "n", following the South Korean initial
+/// sound rule.  An "r" or "h" after a vowel and before a consonant only
+/// marks the vowel in informal spellings and is dropped.  Thus "Lee",
+/// "Yi", "Rhee", and "이" encode to "I", and "Park", "Pak", "Bak", and "박"
+/// to "PAK".
+///
+/// Spaces and hyphens separate words.  Other unknown characters are
+/// handled according to `policy`.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Korean, PhonicsEncoder};
+///
+/// let enc = Korean::new();
+/// assert_eq!(enc.encode("Park").unwrap(), enc.encode("박").unwrap());
+/// ```
+pub struct Korean {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The rewrite rules are compiled at instance instantiation to speed execution at run
+    /// time.
+    rules: RuleSet,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`Korean::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Korean::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Korean {
+    fn new() -> Korean {
+        Korean {
+            special_characters_re: Regex::new(r"[^A-ZŎŬ\x{AC00}-\x{D7A3}'’ \-]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZŎŬ\x{AC00}-\x{D7A3}'’ \-]*").unwrap(),
+            rules: RuleSet::new(RULES, "AEIOUWY"),
+            max_code_len: Korean::MAX_CODE_LEN_DEFAULT,
+            policy: Korean::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Decompose the Hangul syllables and write them in the Revised
+        // Romanization
+        let return_string: String = return_string
+            .chars()
+            .map(|c| match c {
+                '\u{AC00}'..='\u{D7A3}' => {
+                    let s = c as usize - 0xAC00;
+                    format!(
+                        "{}{}{}",
+                        INITIALS[s / 588],
+                        VOWELS[s % 588 / 28],
+                        FINALS[s % 28]
+                    )
+                }
+                _ => c.to_string(),
+            })
+            .collect();
+
+        // Remove the McCune-Reischauer marks
+        let return_string = utils::transliterate(&return_string, MCCUNE_REISCHAUER);
+
+        // Fold each word
+        let return_string: String = return_string
+            .split(&[' ', '-'][..])
+            .map(|word| self.rules.apply(word))
+            .collect();
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl Korean {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Korean {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Korean {
+    fn algorithm(&self) -> &str {
+        "korean"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Korean, PhonicsEncoder};
+
+    #[test]
+    fn test_korean_default_max_code_len() {
+        let e = Korean::new();
+
+        assert_eq!(e.max_code_len, Korean::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_korean_default_policy() {
+        let e = Korean::new();
+
+        assert_eq!(e.policy, Korean::POLICY_DEFAULT);
+    }
+}
@@ -23,8 +23,13 @@
 use regex::Regex;
 
 use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PackedEncoder;
 use crate::PhonicsEncoder;
 use crate::PhonicsError;
+use crate::TaggedEncoder;
 
 /// The Lein name coding procedure.
 ///
@@ -33,9 +38,11 @@ use crate::PhonicsError;
 /// characters are removed from the string in a locale-dependent fashion.
 /// This strips spaces, hyphens, and numbers.  Other letters, such as
 /// 'Ü', may be permissible in the current locale but are unknown to
-/// Lein.  For inputs outside of its known range, an error is returned
-/// If `clean` is `false`, the encoder attempts to process the
-/// strings.  The default value of `clean` is `true`.
+/// Lein.  Inputs outside of its known range are handled according to
+/// `policy`, which by default removes them.  Under
+/// [`CharacterPolicy::Transliterate`], "Ørsted" is encoded as "Orsted"
+/// rather than "rsted", and under [`CharacterPolicy::Reject`], an error is
+/// returned.
 ///
 /// # References
 ///
@@ -63,13 +70,12 @@ pub struct Lein {
 
     /// The Lein specification has a standard maximum length.  However, it may be reasonable to
     /// use either a shorter or longer length, depending on the application.  The default value
-    /// for `max_code_len` is is in [`Lein::MAX_CODE_LEN_DEFAULT`].
+    /// for `max_code_len` is in [`Lein::MAX_CODE_LEN_DEFAULT`].
     pub max_code_len: usize,
 
-    /// If `clean` is `true`, then `encode` will return
-    /// [`PhonicsError::UnknownCharactersFound`]. If `clean` is `false`, then unknown characters
-    /// are ignored.  The default value is [`Lein::CLEAN_DEFAULT`].
-    pub clean: bool,
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Lein::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
 }
 
 impl PhonicsEncoder for Lein {
@@ -78,22 +84,19 @@ impl PhonicsEncoder for Lein {
             special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
             white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
             max_code_len: Lein::MAX_CODE_LEN_DEFAULT,
-            clean: Lein::CLEAN_DEFAULT,
+            policy: Lein::POLICY_DEFAULT,
         }
     }
 
     fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
-        let mut return_string = String::from(source_string);
-
-        // First, uppercase it and test for unprocessable characters
+        // First, apply the character policy and uppercase it
+        let mut return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
         return_string = return_string.to_uppercase();
-        if self.special_characters_re.is_match(&return_string) && self.clean {
-            return Err(PhonicsError::UnknownCharactersFound);
-        }
         let return_string = self.white_space_re.replace_all(&return_string, "");
 
         // Check if the return string is empty yet
-        if return_string == "" {
+        if return_string.is_empty() {
             return Ok("".to_string());
         }
 
@@ -138,12 +141,57 @@ impl Lein {
     pub const MAX_CODE_LEN_DEFAULT: usize = 4;
 
     /// The default value on the handling of special characters.
-    pub const CLEAN_DEFAULT: bool = false;
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Lein {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if options.max_code_len == Some(0) {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "Lein codes are zero-padded and need a max_code_len of at least 1",
+            )));
+        }
+
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl PackedEncoder for Lein {
+    /// Return a letter followed by `max_code_len - 1` digits from "0" to "5".
+    fn key_alphabets(&self) -> Result<Vec<&str>, PhonicsError> {
+        if self.max_code_len == 0 {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "Lein codes have no fixed length to pack with a max_code_len of 0",
+            )));
+        }
+
+        let mut alphabets = vec!["ABCDEFGHIJKLMNOPQRSTUVWXYZ"];
+        alphabets.resize(self.max_code_len, "012345");
+
+        Ok(alphabets)
+    }
+}
+
+impl TaggedEncoder for Lein {
+    fn algorithm(&self) -> &str {
+        "lein"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Lein, PhonicsEncoder};
+    use crate::{CharacterPolicy, Lein, PhonicsEncoder};
 
     #[test]
     fn test_lein_default_max_code_len() {
@@ -163,19 +211,23 @@ mod tests {
     }
 
     #[test]
-    fn test_lein_default_clean() {
+    fn test_lein_default_policy() {
         let e = Lein::new();
 
-        assert_eq!(e.clean, Lein::CLEAN_DEFAULT);
+        assert_eq!(e.policy, Lein::POLICY_DEFAULT);
     }
 
     #[test]
-    fn test_lein_set_clean() {
+    fn test_lein_set_policy() {
         let mut e = Lein::new();
 
-        for i in &[false, true] {
-            e.clean = *i;
-            assert_eq!(e.clean, *i);
+        for i in &[
+            CharacterPolicy::Reject,
+            CharacterPolicy::Drop,
+            CharacterPolicy::Transliterate,
+        ] {
+            e.policy = *i;
+            assert_eq!(e.policy, *i);
         }
     }
 }
@@ -52,22 +52,233 @@
 //! Source Software_, 3(22), 480, 2018.
 //! [doi.org/10.21105/joss.00480](https://doi.org/10.21105/joss.00480).
 
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+mod alpha_sis;
+pub use alpha_sis::AlphaSis;
+
+mod arabic;
+pub use arabic::Arabic;
+
+mod chinese;
+pub use chinese::Chinese;
+
+mod code;
+pub use code::{PhoneticCode, TaggedEncoder};
+
+mod custom;
+pub use custom::{CustomCode, CustomDefinition, CustomEncoder, CustomRule};
+
+mod czech;
+pub use czech::Czech;
+
+mod davidson;
+pub use davidson::Davidson;
+
+mod greek;
+pub use greek::Greek;
+
+mod hebrew;
+pub use hebrew::Hebrew;
+
+mod henry_early;
+pub use henry_early::HenryEarly;
+
+mod indian;
+pub use indian::Indian;
+
+mod italian;
+pub use italian::Italian;
+
+mod japanese;
+pub use japanese::Japanese;
+
+mod korean;
+pub use korean::Korean;
+
 mod lein;
 pub use lein::Lein;
 
+mod packed;
+pub use packed::PackedEncoder;
+
+mod parmar_kumbharana;
+pub use parmar_kumbharana::ParmarKumbharana;
+
+mod phonix;
+pub use phonix::Phonix;
+
+mod polish;
+pub use polish::Polish;
+
+mod registry;
+pub use registry::{encoder_by_name, EncoderOptions, ALGORITHMS};
+
+mod reth_schek;
+pub use reth_schek::RethSchek;
+
+mod russian_metaphone;
+pub use russian_metaphone::RussianMetaphone;
+
+mod sound_d;
+pub use sound_d::SoundD;
+
+mod soundex;
+pub use soundex::{Soundex, SoundexVariant};
+
 mod utils;
+pub use utils::fold_to_ascii;
 
 /// Signals an error has been encountered by one of the encoders implementing
 /// the [`PhonicsEncoder`] trait.
-#[derive(Debug)]
+///
+/// [`PhonicsError`] implements [`std::error::Error`], so it can be returned
+/// with `?` from functions returning `Box<dyn Error>` and wrapped by other
+/// error types.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{CharacterPolicy, Lein, PhonicsEncoder, PhonicsError};
+///
+/// let mut enc = Lein::new();
+/// enc.policy = CharacterPolicy::Reject;
+/// match enc.encode("Müller") {
+///     Err(PhonicsError::UnknownCharactersFound(found)) => {
+///         assert_eq!(found[0].character, 'ü');
+///         assert_eq!(found[0].byte_offset, 1);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PhonicsError {
     /// Signals that an unknown character was found and could not be processed.  Many
     /// phonetic spelling algorithms only accept a limited range of inputs.  For
     /// instance, certain French characters ("ç") may not be accepted by an English
     /// language encoder.  Each encoder may chose to handle these characters by
     /// ignoring them, treating them as equivalent to some other letter, or throwing
-    /// an error.
-    UnknownCharactersFound,
+    /// an error.  Every rejected character is listed, in order, with its position
+    /// in the input.
+    UnknownCharactersFound(Vec<UnknownCharacter>),
+
+    /// Signals that an encoder was given a setting it cannot use, such as a
+    /// maximum code length of zero for an encoder that pads its codes.  The
+    /// message names the setting and why it was rejected.
+    InvalidConfiguration(String),
+
+    /// Signals that a [`PhoneticCode`] could not be read or is not a valid
+    /// code of its algorithm.  The message names the part that was rejected.
+    InvalidCode(String),
+
+    /// Signals that two [`PhoneticCode`]s produced by different algorithms,
+    /// or by one algorithm with different settings, were compared.  The
+    /// message names both.
+    IncompatibleCodes(String),
+
+    /// Signals that there was nothing to encode or parse.  The encoders
+    /// themselves return an empty code for an empty string, so this is for
+    /// callers that need a non-empty input.
+    EmptyInput,
+}
+
+impl fmt::Display for PhonicsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PhonicsError::UnknownCharactersFound(found) => {
+                write!(f, "unknown characters found: ")?;
+                for (i, c) in found.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", c)?;
+                }
+                Ok(())
+            }
+            PhonicsError::InvalidConfiguration(message) => {
+                write!(f, "invalid configuration: {}", message)
+            }
+            PhonicsError::InvalidCode(message) => write!(f, "invalid code: {}", message),
+            PhonicsError::IncompatibleCodes(message) => {
+                write!(f, "incompatible codes: {}", message)
+            }
+            PhonicsError::EmptyInput => write!(f, "empty input"),
+        }
+    }
+}
+
+impl Error for PhonicsError {}
+
+/// A character rejected by an encoder, reported in
+/// [`PhonicsError::UnknownCharactersFound`].
+///
+/// The offsets are into the string given to the encoder, before it is
+/// uppercased or otherwise changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownCharacter {
+    /// The character as it appears in the input.
+    pub character: char,
+
+    /// The offset of the character in bytes, suitable for slicing the input.
+    pub byte_offset: usize,
+
+    /// The offset of the character in characters.
+    pub char_offset: usize,
+}
+
+impl fmt::Display for UnknownCharacter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at byte {} (character {})",
+            self.character, self.byte_offset, self.char_offset
+        )
+    }
+}
+
+/// The handling of characters an encoder cannot process.
+///
+/// Each encoder accepts a limited alphabet, such as "A-Z" for [`Lein`] or
+/// the Cyrillic alphabet for [`RussianMetaphone`].  The policy decides what
+/// happens to any other character in the input.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{CharacterPolicy, Lein, PhonicsEncoder};
+///
+/// let mut enc = Lein::new();
+/// assert_eq!(enc.encode("Ørsted").unwrap(), enc.encode("rsted").unwrap());
+///
+/// enc.policy = CharacterPolicy::Transliterate;
+/// assert_eq!(enc.encode("Ørsted").unwrap(), enc.encode("Orsted").unwrap());
+///
+/// enc.policy = CharacterPolicy::Reject;
+/// assert!(enc.encode("Ørsted").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CharacterPolicy {
+    /// Return [`PhonicsError::UnknownCharactersFound`] listing every
+    /// character that cannot be processed.
+    Reject,
+
+    /// Remove characters that cannot be processed and encode the rest.
+    Drop,
+
+    /// Replace letters that cannot be processed with their closest ASCII
+    /// spelling, such as "U" for "Ü" or "SS" for "ß", and then encode.
+    /// Letters are decomposed to Unicode Normalization Form KD and their
+    /// combining marks removed, and letters with no decomposition, such as
+    /// "Æ", "Ø", "Ł", and "Þ", are spelled out from a table.  The letters of
+    /// the encoder's own alphabet are left alone, and characters with no
+    /// ASCII spelling, or whose ASCII spelling still cannot be processed, are
+    /// removed.
+    Transliterate,
 }
 
 /// A trait for phonetic encoding of a string.
@@ -85,6 +296,11 @@ pub enum PhonicsError {
 ///     return(e.encode("Scully").unwrap());
 /// }
 /// ```
+///
+/// Construction with [`PhonicsEncoder::new`] requires a concrete type, but
+/// the trait is otherwise object safe, so an encoder chosen at run time, such
+/// as one returned by [`encoder_by_name`], can be used as a
+/// `Box<dyn PhonicsEncoder>`.
 pub trait PhonicsEncoder {
     /// Return a new encoder.
     ///
@@ -98,7 +314,9 @@ pub trait PhonicsEncoder {
     ///
     /// let mut enc = Lein::new();
     /// ```
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// Encode a string given in `word` and return the result or error.
     ///
@@ -116,6 +334,156 @@ pub trait PhonicsEncoder {
     /// enc.encode("Mulder");
     /// ```
     fn encode(&self, word: &str) -> Result<String, PhonicsError>;
+
+    /// Encode a string given in `word` and return every code or error.
+    ///
+    /// Some algorithms yield more than one code for a string, such as when
+    /// a letter group may be read in more than one way.  The primary code of
+    /// the [`CodeSet`] is the code returned by [`PhonicsEncoder::encode`].  The
+    /// default returns that code alone, and encoders with alternates
+    /// override it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonics::{AlphaSis, PhonicsEncoder};
+    ///
+    /// let enc = AlphaSis::new();
+    /// let codes = enc.encode_all("Jackson").unwrap();
+    /// assert_eq!(codes.primary(), "37020000000000");
+    /// assert_eq!(codes.alternates(), ["36020000000000"]);
+    /// ```
+    fn encode_all(&self, word: &str) -> Result<CodeSet, PhonicsError> {
+        Ok(CodeSet::new(self.encode(word)?))
+    }
+
+    /// Return whether the strings given in `first` and `second` sound alike,
+    /// or error.
+    ///
+    /// The strings sound alike if any code of one, primary or alternate, is
+    /// a code of the other, as given by [`PhonicsEncoder::encode_all`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonics::{AlphaSis, PhonicsEncoder};
+    ///
+    /// let enc = AlphaSis::new();
+    /// assert_ne!(enc.encode("Czerny").unwrap(), enc.encode("Cherny").unwrap());
+    /// assert!(enc.sounds_alike("Czerny", "Cherny").unwrap());
+    /// ```
+    fn sounds_alike(&self, first: &str, second: &str) -> Result<bool, PhonicsError> {
+        Ok(self
+            .encode_all(first)?
+            .intersects(&self.encode_all(second)?))
+    }
+}
+
+/// The codes of a string under an encoder that may yield more than one.
+///
+/// A [`CodeSet`] has one primary code, the code returned by
+/// [`PhonicsEncoder::encode`], and any number of alternate codes, without
+/// repeats.  Code sets are returned by [`PhonicsEncoder::encode_all`].
+///
+/// # Example
+///
+/// ```
+/// use phonics::CodeSet;
+///
+/// let codes = CodeSet::with_alternates(
+///     String::from("A1"),
+///     vec![String::from("B2"), String::from("A1"), String::from("B2")],
+/// );
+/// assert_eq!(codes.primary(), "A1");
+/// assert_eq!(codes.alternates(), ["B2"]);
+/// assert_eq!(codes.iter().collect::<Vec<_>>(), ["A1", "B2"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CodeSet {
+    primary: String,
+    alternates: Vec<String>,
+}
+
+impl CodeSet {
+    /// Return a code set of the code `primary` alone.
+    pub fn new(primary: String) -> CodeSet {
+        CodeSet {
+            primary,
+            alternates: Vec::new(),
+        }
+    }
+
+    /// Return a code set of the code `primary` and the codes in
+    /// `alternates`, with repeats removed.
+    pub fn with_alternates(primary: String, alternates: Vec<String>) -> CodeSet {
+        let mut codes = CodeSet::new(primary);
+
+        for code in alternates {
+            if !codes.contains(&code) {
+                codes.alternates.push(code);
+            }
+        }
+
+        codes
+    }
+
+    /// Return the primary code.
+    pub fn primary(&self) -> &str {
+        &self.primary
+    }
+
+    /// Return the alternate codes, in the order the encoder gave them.
+    pub fn alternates(&self) -> &[String] {
+        &self.alternates
+    }
+
+    /// Return an iterator over every code, the primary code first.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.primary.as_str()).chain(self.alternates.iter().map(String::as_str))
+    }
+
+    /// Return whether `code` is the primary code or an alternate code.
+    pub fn contains(&self, code: &str) -> bool {
+        self.iter().any(|c| c == code)
+    }
+
+    /// Return whether any code of the set is a code of `other`.
+    ///
+    /// Empty codes, which encoders return for strings with nothing to
+    /// encode, are not compared, so they never intersect.
+    pub fn intersects(&self, other: &CodeSet) -> bool {
+        self.iter()
+            .filter(|code| !code.is_empty())
+            .any(|code| other.contains(code))
+    }
+}
+
+/// A trait for phonetic encoders whose settings can be changed and checked.
+///
+/// Each encoder has its own rules for which settings are valid.  For
+/// instance, [`Lein`] pads its codes to `max_code_len` characters and so
+/// rejects a `max_code_len` of zero, while the language keys, such as
+/// [`Polish`], read zero as no truncation.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Configurable, EncoderOptions, Lein, PhonicsEncoder};
+///
+/// let mut enc = Lein::new();
+/// let options = EncoderOptions {
+///     max_code_len: Some(0),
+///     ..EncoderOptions::default()
+/// };
+/// assert!(enc.configure(&options).is_err());
+/// ```
+pub trait Configurable {
+    /// Apply the settings given in `options`, leaving those that are `None`
+    /// unchanged.
+    ///
+    /// If any setting is invalid, [`PhonicsError::InvalidConfiguration`] is
+    /// returned and no setting is changed.
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError>;
 }
 
 /// A generic factory for phonetic encoders.
@@ -129,6 +497,16 @@ pub trait PhonicsEncoder {
 /// ```
 /// use phonics::Phonics;
 /// ```
+///
+/// The settings of the wrapped encoder can be given with
+/// [`Phonics::builder`]:
+///
+/// ```
+/// use phonics::{Lein, Phonics, PhonicsEncoder};
+///
+/// let enc = Phonics::<Lein>::builder().max_code_len(6).build().unwrap();
+/// assert_eq!(enc.encode("Hilbert").unwrap(), "H34310");
+/// ```
 pub struct Phonics<P: PhonicsEncoder> {
     encoder: P,
 }
@@ -141,4 +519,78 @@ impl<P: PhonicsEncoder> PhonicsEncoder for Phonics<P> {
     fn encode(&self, word: &str) -> Result<String, PhonicsError> {
         self.encoder.encode(word)
     }
+
+    fn encode_all(&self, word: &str) -> Result<CodeSet, PhonicsError> {
+        self.encoder.encode_all(word)
+    }
+}
+
+impl<P: PackedEncoder> PackedEncoder for Phonics<P> {
+    fn key_alphabets(&self) -> Result<Vec<&str>, PhonicsError> {
+        self.encoder.key_alphabets()
+    }
+}
+
+impl<P: TaggedEncoder> TaggedEncoder for Phonics<P> {
+    fn algorithm(&self) -> &str {
+        self.encoder.algorithm()
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        TaggedEncoder::max_code_len(&self.encoder)
+    }
+}
+
+impl<P: PhonicsEncoder + Configurable> Phonics<P> {
+    /// Return a builder for an encoder with settings other than the
+    /// defaults.
+    pub fn builder() -> PhonicsBuilder<P> {
+        PhonicsBuilder {
+            options: EncoderOptions::default(),
+            encoder: PhantomData,
+        }
+    }
+}
+
+/// A builder for a [`Phonics`] encoder with settings other than the
+/// defaults.
+///
+/// The settings are checked by the wrapped encoder when [`PhonicsBuilder::build`]
+/// is called, and settings that are not given keep the encoder's defaults.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Lein, Phonics, PhonicsError};
+///
+/// let enc = Phonics::<Lein>::builder().max_code_len(0).build();
+/// assert!(matches!(enc, Err(PhonicsError::InvalidConfiguration(_))));
+/// ```
+pub struct PhonicsBuilder<P: PhonicsEncoder + Configurable> {
+    options: EncoderOptions,
+    encoder: PhantomData<P>,
+}
+
+impl<P: PhonicsEncoder + Configurable> PhonicsBuilder<P> {
+    /// Set the maximum code length of the encoder.
+    pub fn max_code_len(mut self, max_code_len: usize) -> PhonicsBuilder<P> {
+        self.options.max_code_len = Some(max_code_len);
+        self
+    }
+
+    /// Set the handling of special characters of the encoder.
+    pub fn policy(mut self, policy: CharacterPolicy) -> PhonicsBuilder<P> {
+        self.options.policy = Some(policy);
+        self
+    }
+
+    /// Return the configured encoder, or
+    /// [`PhonicsError::InvalidConfiguration`] if the encoder rejects a
+    /// setting.
+    pub fn build(&self) -> Result<Phonics<P>, PhonicsError> {
+        let mut encoder = P::new();
+        encoder.configure(&self.options)?;
+
+        Ok(Phonics { encoder })
+    }
 }
@@ -0,0 +1,143 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use std::convert::TryFrom;
+use std::mem;
+
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+
+/// A trait for phonetic encoders whose codes can be packed into integer keys.
+///
+/// Encoders with a fixed code length and a fixed alphabet at each position,
+/// such as [`Lein`](crate::Lein), have a small number of codes, and each
+/// code can be stored as a number rather than a string.  The code is read
+/// as a number whose digits are the positions of its characters in
+/// [`PackedEncoder::key_alphabets`], so a key can be unpacked to its code,
+/// and keys sort in the same order as their codes.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Lein, PackedEncoder, PhonicsEncoder};
+///
+/// let enc = Lein::new();
+/// let key: u16 = enc.encode_key("Hilbert").unwrap();
+/// assert_eq!(key, 1647);
+/// assert_eq!(enc.unpack(key.into()).unwrap(), "H343");
+/// assert!(key < enc.encode_key::<u16>("Knuth").unwrap());
+/// ```
+pub trait PackedEncoder: PhonicsEncoder {
+    /// Return the characters allowed at each position of a code, each in
+    /// ascending order, or [`PhonicsError::InvalidConfiguration`] if the
+    /// settings of the encoder do not give fixed-width codes.
+    fn key_alphabets(&self) -> Result<Vec<&str>, PhonicsError>;
+
+    /// Return the number of keys, which are `0` to one less than the number,
+    /// or [`PhonicsError::InvalidConfiguration`] if they do not fit in a
+    /// [`u64`].
+    fn key_count(&self) -> Result<u64, PhonicsError> {
+        let mut count: u64 = 1;
+        for alphabet in self.key_alphabets()? {
+            count = count
+                .checked_mul(alphabet.chars().count() as u64)
+                .ok_or_else(|| {
+                    PhonicsError::InvalidConfiguration(String::from(
+                        "the codes have too many characters to pack into 8 bytes",
+                    ))
+                })?;
+        }
+
+        Ok(count)
+    }
+
+    /// Return the key of the code given in `code`.
+    ///
+    /// If the code is empty, [`PhonicsError::EmptyInput`] is returned, and if
+    /// it is not a code of the encoder with its current settings,
+    /// [`PhonicsError::InvalidCode`] is returned.
+    fn pack(&self, code: &str) -> Result<u64, PhonicsError> {
+        if code.is_empty() {
+            return Err(PhonicsError::EmptyInput);
+        }
+
+        self.key_count()?;
+        let alphabets = self.key_alphabets()?;
+        let invalid = || PhonicsError::InvalidCode(format!("\"{}\" cannot be packed", code));
+        if code.chars().count() != alphabets.len() {
+            return Err(invalid());
+        }
+
+        let mut key: u64 = 0;
+        for (c, alphabet) in code.chars().zip(alphabets) {
+            let digit = alphabet.chars().position(|a| a == c).ok_or_else(invalid)?;
+            key = key * alphabet.chars().count() as u64 + digit as u64;
+        }
+
+        Ok(key)
+    }
+
+    /// Return the code of the key given in `key`, or
+    /// [`PhonicsError::InvalidCode`] if it is not a key of the encoder with its
+    /// current settings.
+    fn unpack(&self, key: u64) -> Result<String, PhonicsError> {
+        if key >= self.key_count()? {
+            return Err(PhonicsError::InvalidCode(format!(
+                "{} is not a key of these codes",
+                key
+            )));
+        }
+
+        let mut rest = key;
+        let mut code = Vec::new();
+        for alphabet in self.key_alphabets()?.iter().rev() {
+            let radix = alphabet.chars().count() as u64;
+            code.push(alphabet.chars().nth((rest % radix) as usize).unwrap());
+            rest /= radix;
+        }
+
+        Ok(code.iter().rev().collect())
+    }
+
+    /// Encode a string given in `word` and return its key or error.
+    ///
+    /// The key type `K` must hold every key, such as a [`u16`] for four
+    /// character Lein codes, or [`PhonicsError::InvalidConfiguration`] is
+    /// returned whatever the word.  If the string has nothing to encode,
+    /// [`PhonicsError::EmptyInput`] is returned.
+    fn encode_key<K: TryFrom<u64>>(&self, word: &str) -> Result<K, PhonicsError>
+    where
+        Self: Sized,
+    {
+        if K::try_from(self.key_count()? - 1).is_err() {
+            return Err(PhonicsError::InvalidConfiguration(format!(
+                "{} keys do not fit in {} bytes",
+                self.key_count()?,
+                mem::size_of::<K>()
+            )));
+        }
+
+        let key = self.pack(&self.encode(word)?)?;
+        K::try_from(key)
+            .map_err(|_| PhonicsError::InvalidCode(format!("{} cannot be converted", key)))
+    }
+}
@@ -0,0 +1,187 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Letter groups and their replacements, longest first.
+const REPLACEMENTS: &[(&str, &str)] = &[
+    ("OUGH", "F"),
+    ("DGE", "J"),
+    ("GHT", "T"),
+    ("OUL", "U"),
+    ("CE", "S"),
+    ("CI", "S"),
+    ("CK", "K"),
+    ("CY", "S"),
+    ("GE", "J"),
+    ("GI", "J"),
+    ("GN", "N"),
+    ("GY", "J"),
+    ("KN", "N"),
+    ("PN", "N"),
+    ("SH", "S"),
+    ("WR", "R"),
+];
+
+/// The Parmar-Kumbharana procedure.
+///
+/// Parmar and Kumbharana's algorithm collapses repeated letters, then
+/// replaces letter groups from left to right: "OUGH" becomes "F", "DGE"
+/// and the soft "G" become "J", the soft "C" becomes "S", "SH" becomes
+/// "S", the silent letters of "GHT", "GN", "KN", "PN", and "WR" are
+/// dropped, and so on.  Finally, the vowels and "Y" are removed from all
+/// but the first letter.
+///
+/// The Parmar-Kumbharana algorithm is only defined for inputs over the
+/// standard English alphabet, _i.e._, "A-Z".  Other characters are handled
+/// according to `policy`.  See the [`Indian`](crate::Indian) encoder for a
+/// variant adapted to romanized Indian names.
+///
+/// # References
+///
+/// Vimal P. Parmar and C. K. Kumbharana. "Study Existing Various Phonetic
+/// Algorithms and Designing and Development of a Working Model for the New
+/// Developed Algorithm and Comparison by Implementing It with Existing
+/// Algorithm(s)." _International Journal of Computer Applications_ 98, no.
+/// 19 (2014): 45-49.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{ParmarKumbharana, PhonicsEncoder};
+///
+/// let enc = ParmarKumbharana::new();
+/// assert_eq!(enc.encode("Knight").unwrap(), "NT");
+/// ```
+pub struct ParmarKumbharana {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`ParmarKumbharana::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`ParmarKumbharana::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for ParmarKumbharana {
+    fn new() -> ParmarKumbharana {
+        ParmarKumbharana {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            max_code_len: ParmarKumbharana::MAX_CODE_LEN_DEFAULT,
+            policy: ParmarKumbharana::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Remove duplicate consecutive characters, then replace letter groups
+        let return_string = utils::remove_duplicate_characters(&return_string);
+        let return_string = utils::transliterate(&return_string, REPLACEMENTS);
+
+        // Remove the vowels after the first letter
+        let mut return_string: String = return_string
+            .char_indices()
+            .filter(|(i, c)| *i == 0 || !"AEIOUY".contains(*c))
+            .map(|(_, c)| c)
+            .collect();
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl ParmarKumbharana {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for ParmarKumbharana {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for ParmarKumbharana {
+    fn algorithm(&self) -> &str {
+        "parmar_kumbharana"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParmarKumbharana, PhonicsEncoder};
+
+    #[test]
+    fn test_parmar_kumbharana_default_max_code_len() {
+        let e = ParmarKumbharana::new();
+
+        assert_eq!(e.max_code_len, ParmarKumbharana::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_parmar_kumbharana_default_policy() {
+        let e = ParmarKumbharana::new();
+
+        assert_eq!(e.policy, ParmarKumbharana::POLICY_DEFAULT);
+    }
+}
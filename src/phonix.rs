@@ -0,0 +1,486 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::utils::{Context, Position, Rule, RuleSet};
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// The PHONIX letter-group substitutions, as transcribed from Gadd (1990)
+/// in the Text::Phonetic module, in the order they are applied.  Each may
+/// be restricted to the initial, medial, or final position and to a vowel
+/// or consonant context before or after it.
+const RULES: &[Rule] = &[
+    Rule::new("DG", "G"),
+    Rule::new("CO", "KO"),
+    Rule::new("CA", "KA"),
+    Rule::new("CU", "KU"),
+    Rule::new("CY", "SI"),
+    Rule::new("CI", "SI"),
+    Rule::new("CE", "SE"),
+    Rule::new("CL", "KL")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("CK", "K"),
+    Rule::new("GC", "K").at(Position::Final),
+    Rule::new("JC", "K").at(Position::Final),
+    Rule::new("CHR", "KR")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("CR", "KR")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("WR", "R").at(Position::Initial),
+    Rule::new("NC", "NK"),
+    Rule::new("CT", "KT"),
+    Rule::new("PH", "F"),
+    Rule::new("AA", "AR"),
+    Rule::new("SCH", "SH"),
+    Rule::new("BTL", "TL"),
+    Rule::new("GHT", "T"),
+    Rule::new("AUGH", "ARF"),
+    Rule::new("LJ", "LD")
+        .after(Context::Vowel)
+        .before(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("LOUGH", "LOW"),
+    Rule::new("Q", "KW").at(Position::Initial),
+    Rule::new("KN", "N").at(Position::Initial),
+    Rule::new("GN", "N").at(Position::Final),
+    Rule::new("GHN", "N"),
+    Rule::new("GNE", "N").at(Position::Final),
+    Rule::new("GHNE", "NE"),
+    Rule::new("GNES", "NS").at(Position::Final),
+    Rule::new("GN", "N").at(Position::Initial),
+    Rule::new("GN", "N")
+        .before(Context::Consonant)
+        .at(Position::Medial),
+    Rule::new("PS", "S").at(Position::Initial),
+    Rule::new("PT", "T").at(Position::Initial),
+    Rule::new("CZ", "C").at(Position::Initial),
+    Rule::new("WZ", "Z")
+        .after(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("CZ", "CH").at(Position::Medial),
+    Rule::new("LZ", "LSH"),
+    Rule::new("RZ", "RSH"),
+    Rule::new("Z", "S")
+        .before(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("ZZ", "TS"),
+    Rule::new("Z", "TS")
+        .after(Context::Consonant)
+        .at(Position::Medial),
+    Rule::new("HROUG", "REW"),
+    Rule::new("OUGH", "OF"),
+    Rule::new("Q", "KW")
+        .after(Context::Vowel)
+        .before(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("J", "Y")
+        .after(Context::Vowel)
+        .before(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("YJ", "Y")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("GH", "G").at(Position::Initial),
+    Rule::new("GH", "E")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("CY", "S").at(Position::Initial),
+    Rule::new("NX", "NKS"),
+    Rule::new("PF", "F").at(Position::Initial),
+    Rule::new("DT", "T").at(Position::Final),
+    Rule::new("TL", "TIL").at(Position::Final),
+    Rule::new("DL", "DIL").at(Position::Final),
+    Rule::new("YTH", "ITH"),
+    Rule::new("TJ", "CH")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("TSJ", "CH")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("TS", "T")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("TCH", "CH"),
+    Rule::new("WSK", "VSKIE")
+        .after(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("WSK", "VSKIE")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("MN", "N")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("PN", "N")
+        .before(Context::Vowel)
+        .at(Position::Initial),
+    Rule::new("STL", "SL")
+        .after(Context::Vowel)
+        .at(Position::Medial),
+    Rule::new("STL", "SL")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("TNT", "ENT").at(Position::Final),
+    Rule::new("EAUX", "OH").at(Position::Final),
+    Rule::new("EXCI", "ECS"),
+    Rule::new("X", "ECS"),
+    Rule::new("NED", "ND").at(Position::Final),
+    Rule::new("JR", "DR"),
+    Rule::new("EE", "EA").at(Position::Final),
+    Rule::new("ZS", "S"),
+    Rule::new("R", "AH")
+        .after(Context::Vowel)
+        .before(Context::Consonant)
+        .at(Position::Medial),
+    Rule::new("R", "AH")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("HR", "AH")
+        .after(Context::Vowel)
+        .before(Context::Consonant)
+        .at(Position::Medial),
+    Rule::new("HR", "AH")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("RE", "AR").at(Position::Final),
+    Rule::new("R", "AH")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("LLE", "LE"),
+    Rule::new("LE", "ILE")
+        .after(Context::Consonant)
+        .at(Position::Final),
+    Rule::new("LES", "ILES")
+        .after(Context::Consonant)
+        .at(Position::Final),
+    Rule::new("E", "").at(Position::Final),
+    Rule::new("ES", "S").at(Position::Final),
+    Rule::new("SS", "AS")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("MB", "M")
+        .after(Context::Vowel)
+        .at(Position::Final),
+    Rule::new("MPTS", "MPS"),
+    Rule::new("MPS", "MS"),
+    Rule::new("MPT", "MT"),
+];
+
+/// The PHONIX procedure.
+///
+/// PHONIX extends Soundex with a table of letter-group substitutions that
+/// are applied before coding.  Each substitution may be restricted to the
+/// initial, medial, or final position of the name and to a vowel or
+/// consonant context, so that, for instance, "KN" is reduced to "N" only at
+/// the start of a name and "R" after a vowel is treated as silent.  After
+/// substitution, the first letter is retained, or replaced by "V" if it is
+/// a vowel or "Y", and the remaining letters are coded by Soundex-like
+/// classes with "F" and "V" and "S", "X", and "Z" in classes of their own.
+/// Repeated codes are collapsed and vowels are removed, and the code is
+/// zero-padded and truncated to `max_code_len` characters, eight by default.
+///
+/// The substitutions follow the transcription of Gadd's table in the
+/// Text::Phonetic module for Perl, the table most later implementations
+/// share, with each entry's position and letter context.  That
+/// transcription has 91 substitutions, fewer than the more than 160 of the
+/// table in Gadd (1990), and only those 91 are implemented here, since the
+/// remaining entries could not be checked against the published table.
+/// Codes may therefore differ from Gadd's for names using the omitted
+/// letter groups.
+///
+/// The PHONIX algorithm is only defined for inputs over the standard
+/// English alphabet, _i.e._, "A-Z".  Other characters are handled according
+/// to `policy`.
+///
+/// # References
+///
+/// T. N. Gadd. "'Fisching fore werds': Phonetic retrieval of written text in
+/// information systems." _Program_, 22(3), 222-237, 1988.
+///
+/// T. N. Gadd. "PHONIX: The algorithm." _Program_, 24(4), 363-369, 1990.
+///
+/// M. Kollár. Text::Phonetic::Phonix, in Text::Phonetic, a Perl module
+/// distributed through CPAN.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{Phonix, PhonicsEncoder};
+///
+/// let enc = Phonix::new();
+/// assert_eq!(enc.encode("Christopher").unwrap(), "K6837000");
+/// ```
+pub struct Phonix {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The rewrite rules are compiled at instance instantiation to speed execution at run
+    /// time.
+    rules: RuleSet,
+
+    /// The PHONIX specification has a standard maximum length.  However, it may be reasonable
+    /// to use either a shorter or longer length, depending on the application.  The default
+    /// value for `max_code_len` is in [`Phonix::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Phonix::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Phonix {
+    fn new() -> Phonix {
+        Phonix {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            rules: RuleSet::new(RULES, "AEIOU"),
+            max_code_len: Phonix::MAX_CODE_LEN_DEFAULT,
+            policy: Phonix::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Apply the letter-group substitutions
+        let return_string = self.rules.apply(&return_string);
+
+        // Check if the return string is empty yet
+        if return_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        // First character of key = first character of name, or V for a vowel
+        let mut first_char = return_string.chars().next().unwrap();
+        if "AEIOUY".contains(first_char) {
+            first_char = 'V';
+        }
+        let return_string = utils::strip_first_char(&return_string);
+
+        // A, E, I, O, U, H, W, Y -> 0; B, P -> 1; C, G, J, K, Q -> 2;
+        // D, T -> 3; L -> 4; M, N -> 5; R -> 6; F, V -> 7; S, X, Z -> 8
+        let return_string = utils::transform_characters(&return_string, "AEIOUHWY", "0");
+        let return_string = utils::transform_characters(&return_string, "BP", "1");
+        let return_string = utils::transform_characters(&return_string, "CGJKQ", "2");
+        let return_string = utils::transform_characters(&return_string, "DT", "3");
+        let return_string = utils::transform_characters(&return_string, "L", "4");
+        let return_string = utils::transform_characters(&return_string, "MN", "5");
+        let return_string = utils::transform_characters(&return_string, "R", "6");
+        let return_string = utils::transform_characters(&return_string, "FV", "7");
+        let return_string = utils::transform_characters(&return_string, "SXZ", "8");
+
+        // Remove duplicate consecutive codes, then the vowels
+        let return_string = utils::remove_duplicate_characters(&return_string);
+        let return_string = utils::transform_characters(&return_string, "0", "");
+
+        // Append word except for first character to first
+        let mut return_string = format!("{}{}", first_char, return_string);
+
+        // Zero-pad and truncate to requested length
+        for _ in 1..self.max_code_len {
+            return_string.push('0');
+        }
+        let return_string = return_string.as_str()[0..(self.max_code_len)].to_string();
+
+        Ok(return_string)
+    }
+}
+
+impl Phonix {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 8;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for Phonix {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if options.max_code_len == Some(0) {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "PHONIX codes are zero-padded and need a max_code_len of at least 1",
+            )));
+        }
+
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for Phonix {
+    fn algorithm(&self) -> &str {
+        "phonix"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PhonicsEncoder, Phonix};
+
+    #[test]
+    fn test_phonix_default_max_code_len() {
+        let e = Phonix::new();
+
+        assert_eq!(e.max_code_len, Phonix::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_phonix_default_policy() {
+        let e = Phonix::new();
+
+        assert_eq!(e.policy, Phonix::POLICY_DEFAULT);
+    }
+
+    #[test]
+    fn test_phonix_rules() {
+        let e = Phonix::new();
+
+        // Each rule of the table, with the later rules also applied
+        for (before, after) in &[
+            ("DGE", "G"),
+            ("COB", "KOB"),
+            ("CAB", "KAB"),
+            ("CUB", "KUB"),
+            ("CYB", "SIB"),
+            ("CIB", "SIB"),
+            ("CEB", "SEB"),
+            ("CLAB", "KLAB"),
+            ("CLB", "CLB"),
+            ("ACK", "AK"),
+            ("BAGC", "BAK"),
+            ("BAJC", "BAK"),
+            ("CHRIS", "KRIS"),
+            ("CHRS", "CHRS"),
+            ("CRAB", "KRAB"),
+            ("CRB", "CRB"),
+            ("WRAB", "RAB"),
+            ("BANC", "BANK"),
+            ("BACT", "BAKT"),
+            ("PHIL", "FIL"),
+            ("BAAB", "BAAHB"),
+            ("SCHAB", "SHAB"),
+            ("SUBTLE", "SUTIL"),
+            ("BIGHT", "BIT"),
+            ("NAUGH", "NAAHF"),
+            ("ALJA", "ALDA"),
+            ("LOUGH", "LOW"),
+            ("QUAD", "KWUAD"),
+            ("KNAB", "NAB"),
+            ("SIGN", "SIN"),
+            ("GHNAB", "NAB"),
+            ("SIGNE", "SIN"),
+            ("GHNE", "N"),
+            ("SIGNES", "SINS"),
+            ("GNAB", "NAB"),
+            ("AGNB", "ANB"),
+            ("AGNA", "AGNA"),
+            ("PSAL", "SAL"),
+            ("PTAB", "TAB"),
+            ("CZAB", "CAB"),
+            ("AWZA", "ASA"),
+            ("ACZA", "ACHA"),
+            ("ALZ", "ALSH"),
+            ("ARZ", "AAHSH"),
+            ("AZA", "ASA"),
+            ("AZZ", "ATS"),
+            ("ALZA", "ALSHA"),
+            ("THROUGH", "TREWH"),
+            ("ROUGH", "ROF"),
+            ("AQA", "AKWA"),
+            ("AJA", "AYA"),
+            ("BJA", "BJA"),
+            ("YJA", "YA"),
+            ("GHAB", "GAB"),
+            ("BAGH", "BA"),
+            ("CYAB", "SIAB"),
+            ("ANX", "ANKS"),
+            ("PFAB", "FAB"),
+            ("BADT", "BAT"),
+            ("BATL", "BATIL"),
+            ("BADL", "BADIL"),
+            ("SMYTH", "SMITH"),
+            ("TJAB", "CHAB"),
+            ("TSJAB", "CHAB"),
+            ("TSAB", "TAB"),
+            ("BATCH", "BACH"),
+            ("BAWSKA", "BAVSKIEA"),
+            ("BAWSK", "BAVSKI"),
+            ("MNAB", "NAB"),
+            ("PNAB", "NAB"),
+            ("BASTLA", "BASLA"),
+            ("BASTL", "BASTIL"),
+            ("BATNT", "BAENT"),
+            ("BEAUX", "BOH"),
+            ("EXCITE", "EECSSIT"),
+            ("AXA", "AECSA"),
+            ("BANED", "BAND"),
+            ("JRAB", "DRAB"),
+            ("BEE", "BEA"),
+            ("ZSAB", "SAB"),
+            ("BARD", "BAAHD"),
+            ("BAR", "BAAH"),
+            ("BAHRD", "BAAHD"),
+            ("BAHR", "BAAH"),
+            ("BORE", "BOAAH"),
+            ("BALLE", "BAL"),
+            ("BABLE", "BABIL"),
+            ("BABLES", "BABILS"),
+            ("BABE", "BAB"),
+            ("BABES", "BABS"),
+            ("BASS", "BAAS"),
+            ("BAMB", "BAM"),
+            ("MPTS", "MS"),
+            ("MPS", "MS"),
+            ("MPT", "MT"),
+        ] {
+            assert_eq!(e.rules.apply(before), *after, "{}", before);
+        }
+    }
+}
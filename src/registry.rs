@@ -0,0 +1,179 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::AlphaSis;
+use crate::Arabic;
+use crate::CharacterPolicy;
+use crate::Chinese;
+use crate::Configurable;
+use crate::Czech;
+use crate::Davidson;
+use crate::Greek;
+use crate::Hebrew;
+use crate::HenryEarly;
+use crate::Indian;
+use crate::Italian;
+use crate::Japanese;
+use crate::Korean;
+use crate::Lein;
+use crate::ParmarKumbharana;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::Phonix;
+use crate::Polish;
+use crate::RethSchek;
+use crate::RussianMetaphone;
+use crate::SoundD;
+use crate::Soundex;
+use crate::SoundexVariant;
+
+/// The names of the algorithms available from [`encoder_by_name`].
+pub const ALGORITHMS: &[&str] = &[
+    "alpha_sis",
+    "arabic",
+    "chinese",
+    "czech",
+    "davidson",
+    "greek",
+    "hebrew",
+    "henry_early",
+    "indian",
+    "italian",
+    "japanese",
+    "korean",
+    "lein",
+    "parmar_kumbharana",
+    "phonix",
+    "polish",
+    "reth_schek",
+    "russian_metaphone",
+    "sound_d",
+    "soundex",
+    "soundex_mysql",
+    "soundex_oracle",
+    "soundex_sql_server",
+];
+
+/// Settings applied to an encoder returned by [`encoder_by_name`].
+///
+/// A setting left as `None` keeps the encoder's default.  The settings are
+/// those every encoder has.  A variant, such as that of [`Soundex`], is
+/// chosen by its name in [`ALGORITHMS`] or by the encoder's own
+/// constructor, such as [`Soundex::with_variant`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EncoderOptions {
+    /// The maximum code length, as the encoder's `max_code_len`.
+    pub max_code_len: Option<usize>,
+
+    /// The handling of special characters, as the encoder's `policy`.
+    pub policy: Option<CharacterPolicy>,
+}
+
+/// Configure `enc` with `options` and box it.
+fn configure<E: PhonicsEncoder + Configurable + 'static>(
+    mut enc: E,
+    options: &EncoderOptions,
+) -> Result<Box<dyn PhonicsEncoder>, PhonicsError> {
+    enc.configure(options)?;
+
+    Ok(Box::new(enc))
+}
+
+/// Return the encoder named `name`, configured with `options`.
+///
+/// The names are listed in [`ALGORITHMS`].  The Soundex variants are
+/// available as "soundex", which follows the Census rules, and as
+/// "soundex_sql_server" and "soundex_mysql".  "soundex_oracle" is another
+/// name for "soundex", since Oracle Database follows the Census rules.  If
+/// `name` is not known, or the encoder rejects a setting in `options`, such
+/// as a `max_code_len` for the Davidson consonant code,
+/// [`PhonicsError::InvalidConfiguration`] is returned.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{encoder_by_name, EncoderOptions};
+///
+/// let options = EncoderOptions {
+///     max_code_len: Some(6),
+///     ..EncoderOptions::default()
+/// };
+/// let enc = encoder_by_name("lein", &options).unwrap();
+/// assert_eq!(enc.encode("Hilbert").unwrap(), "H34310");
+/// ```
+pub fn encoder_by_name(
+    name: &str,
+    options: &EncoderOptions,
+) -> Result<Box<dyn PhonicsEncoder>, PhonicsError> {
+    match name {
+        "alpha_sis" => configure(AlphaSis::new(), options),
+        "arabic" => configure(Arabic::new(), options),
+        "chinese" => configure(Chinese::new(), options),
+        "czech" => configure(Czech::new(), options),
+        "davidson" => configure(Davidson::new(), options),
+        "greek" => configure(Greek::new(), options),
+        "hebrew" => configure(Hebrew::new(), options),
+        "henry_early" => configure(HenryEarly::new(), options),
+        "indian" => configure(Indian::new(), options),
+        "italian" => configure(Italian::new(), options),
+        "japanese" => configure(Japanese::new(), options),
+        "korean" => configure(Korean::new(), options),
+        "lein" => configure(Lein::new(), options),
+        "parmar_kumbharana" => configure(ParmarKumbharana::new(), options),
+        "phonix" => configure(Phonix::new(), options),
+        "polish" => configure(Polish::new(), options),
+        "reth_schek" => configure(RethSchek::new(), options),
+        "russian_metaphone" => configure(RussianMetaphone::new(), options),
+        "sound_d" => configure(SoundD::new(), options),
+        "soundex" | "soundex_oracle" => {
+            configure(Soundex::with_variant(SoundexVariant::Census), options)
+        }
+        "soundex_mysql" => configure(Soundex::with_variant(SoundexVariant::MySql), options),
+        "soundex_sql_server" => {
+            configure(Soundex::with_variant(SoundexVariant::SqlServer), options)
+        }
+        _ => Err(PhonicsError::InvalidConfiguration(format!(
+            "unknown algorithm \"{}\"",
+            name
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{encoder_by_name, EncoderOptions, ALGORITHMS};
+
+    #[test]
+    fn test_registry_default_options() {
+        let options = EncoderOptions::default();
+
+        assert_eq!(options.max_code_len, None);
+        assert_eq!(options.policy, None);
+    }
+
+    #[test]
+    fn test_registry_all_algorithms() {
+        for name in ALGORITHMS {
+            assert!(encoder_by_name(name, &EncoderOptions::default()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,250 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// The umlauts and their two-letter spellings.
+const UMLAUTS: &[(&str, &str)] = &[("Ä", "AE"), ("Ö", "OE"), ("Ü", "UE")];
+
+/// Letter groups and their replacements, longest first.  At each position,
+/// the first matching group is replaced and the scan moves on by a single
+/// letter, so a replacement may take part in the next match.  "CH" is kept
+/// as it is, so its "C" is not read as "G", and becomes "S" after the scan.
+const REPLACEMENTS: &[(&str, &str)] = &[
+    ("AEH", "E"),
+    ("AEU", "OI"),
+    ("CHS", "X"),
+    ("CKS", "X"),
+    ("IEH", "I"),
+    ("OEH", "OE"),
+    ("SCH", "CH"),
+    ("TIU", "TIO"),
+    ("UEH", "UE"),
+    ("ZIO", "TIO"),
+    ("ZIU", "TIO"),
+    ("AA", "A"),
+    ("AE", "E"),
+    ("AH", "A"),
+    ("AY", "AI"),
+    ("BB", "B"),
+    ("BP", "B"),
+    ("CC", "G"),
+    ("CH", "CH"),
+    ("CK", "G"),
+    ("DD", "D"),
+    ("DT", "D"),
+    ("EE", "E"),
+    ("EH", "E"),
+    ("EI", "AI"),
+    ("EU", "OI"),
+    ("EY", "AI"),
+    ("FF", "F"),
+    ("GG", "G"),
+    ("GK", "G"),
+    ("GS", "X"),
+    ("IE", "I"),
+    ("IH", "I"),
+    ("KG", "G"),
+    ("KK", "G"),
+    ("KS", "X"),
+    ("KW", "QU"),
+    ("LL", "L"),
+    ("MM", "M"),
+    ("NN", "N"),
+    ("OH", "O"),
+    ("OO", "O"),
+    ("PB", "B"),
+    ("PH", "F"),
+    ("PP", "B"),
+    ("RR", "R"),
+    ("SS", "S"),
+    ("SZ", "S"),
+    ("TH", "D"),
+    ("TT", "D"),
+    ("TZ", "Z"),
+    ("UH", "U"),
+    ("C", "G"),
+    ("K", "G"),
+    ("P", "B"),
+    ("T", "D"),
+    ("V", "F"),
+    ("W", "V"),
+    ("Y", "I"),
+];
+
+/// The Reth-Schek phonetic code.
+///
+/// The Reth-Schek code was developed at the IBM Heidelberg Scientific
+/// Center for similarity searches over German names.  The umlauts are
+/// spelled out, and the name is then scanned from left to right, replacing
+/// letter groups that are pronounced alike: doubled letters are collapsed,
+/// "P" and "T" become "B" and "D", "C" and "K" become "G", "PH" becomes
+/// "F", "EI", "EY", and "AY" become "AI", and a lengthening "H" is dropped,
+/// among others.  Finally, the remaining "CH" becomes "S", a final "ER" or
+/// "EL" loses its "E", and a final "H" is dropped.  The result is a
+/// normalized spelling rather than a numeric code, so "Meyer", "Maier", and
+/// "Mayer" all encode to "MAIR".
+///
+/// The Reth-Schek code is only defined for inputs over the German alphabet,
+/// _i.e._, "A-Z", "Ä", "Ö", "Ü", and "ß".  Other characters are handled
+/// according to `policy`.
+///
+/// # References
+///
+/// Hans-Peter von Reth and Hans-Jörg Schek. "Eine Zugriffsmethode für die
+/// phonetische Ähnlichkeitssuche." Technical Report 77.03.002, IBM
+/// Heidelberg Scientific Center, 1977.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{PhonicsEncoder, RethSchek};
+///
+/// let enc = RethSchek::new();
+/// assert_eq!(enc.encode("Schmidt").unwrap(), "SMID");
+/// ```
+pub struct RethSchek {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`RethSchek::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`RethSchek::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for RethSchek {
+    fn new() -> RethSchek {
+        RethSchek {
+            special_characters_re: Regex::new(r"[^A-ZÄÖÜ]").unwrap(),
+            white_space_re: Regex::new(r"[^A-ZÄÖÜ]*").unwrap(),
+            max_code_len: RethSchek::MAX_CODE_LEN_DEFAULT,
+            policy: RethSchek::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it; "ß"
+        // uppercases to "SS"
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Spell out the umlauts
+        let mut return_string = utils::transliterate(&return_string, UMLAUTS);
+
+        // Replace letter groups, moving on by one letter after each
+        let mut pos = 0;
+        while pos < return_string.len() {
+            if let Some((src, dst)) = REPLACEMENTS
+                .iter()
+                .find(|(src, _)| return_string[pos..].starts_with(src))
+            {
+                return_string.replace_range(pos..pos + src.len(), dst);
+            }
+            pos += 1;
+        }
+
+        // CH is pronounced as S, and final ER, EL, and H are reduced
+        let mut return_string = return_string.replace("CH", "S");
+        if return_string.ends_with("ER") || return_string.ends_with("EL") {
+            return_string.remove(return_string.len() - 2);
+        } else if return_string.ends_with('H') {
+            return_string.pop();
+        }
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl RethSchek {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for RethSchek {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for RethSchek {
+    fn algorithm(&self) -> &str {
+        "reth_schek"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PhonicsEncoder, RethSchek};
+
+    #[test]
+    fn test_reth_schek_default_max_code_len() {
+        let e = RethSchek::new();
+
+        assert_eq!(e.max_code_len, RethSchek::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_reth_schek_default_policy() {
+        let e = RethSchek::new();
+
+        assert_eq!(e.policy, RethSchek::POLICY_DEFAULT);
+    }
+}
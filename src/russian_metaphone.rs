@@ -0,0 +1,219 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Common surname endings and the digits or symbols that replace them.
+/// Longer endings precede the shorter endings they contain.
+const ENDINGS: &[(&str, &str)] = &[
+    ("ОВСКИЙ", "@"),
+    ("ЕВСКИЙ", "#"),
+    ("ОВСКАЯ", "$"),
+    ("ЕВСКАЯ", "%"),
+    ("ИЕВА", "9"),
+    ("ЕЕВА", "9"),
+    ("ОВА", "9"),
+    ("ЕВА", "9"),
+    ("ИНА", "1"),
+    ("ИЕВ", "4"),
+    ("ЕЕВ", "4"),
+    ("НКО", "3"),
+    ("ОВ", "4"),
+    ("ЕВ", "4"),
+    ("АЯ", "6"),
+    ("ИЙ", "7"),
+    ("ЫЙ", "7"),
+    ("ЫХ", "5"),
+    ("ИХ", "5"),
+    ("ИН", "8"),
+    ("ИК", "2"),
+    ("ЕК", "2"),
+    ("УК", "0"),
+    ("ЮК", "0"),
+];
+
+/// Vowel reductions.  Unstressed "о" is pronounced as "а", and the
+/// iotated and front vowels collapse onto "и" and "у".
+const VOWELS: &[(&str, &str)] = &[
+    ("ЙО", "И"),
+    ("ИО", "И"),
+    ("ЙЕ", "И"),
+    ("ИЕ", "И"),
+    ("О", "А"),
+    ("Ы", "А"),
+    ("Я", "А"),
+    ("Е", "И"),
+    ("Ё", "И"),
+    ("Э", "И"),
+    ("Ю", "У"),
+];
+
+/// Consonant clusters pronounced as one sound and the silent signs.
+const CONSONANTS: &[(&str, &str)] = &[("ТС", "Ц"), ("ДС", "Ц"), ("Ь", ""), ("Ъ", "")];
+
+/// The Russian Metaphone.
+///
+/// The Russian Metaphone is defined over the Cyrillic alphabet and is
+/// applied to Cyrillic input directly.  Common surname endings, such as
+/// "-ов", "-ева", and "-овский", are first compressed to a single digit or
+/// symbol.  Unstressed vowels are then reduced: "о", "ы", and "я" become
+/// "а"; "е", "ё", and "э" merge into "и"; and "ю" becomes "у".  Voiced
+/// consonants are devoiced at the end of the word and before a voiceless
+/// consonant, the soft and hard signs are removed, and repeated letters
+/// are collapsed.  The result is written in Cyrillic.
+///
+/// Characters outside the Russian alphabet, including Latin letters, are
+/// handled according to `policy`.  Latin letters have no Cyrillic
+/// spelling, so [`CharacterPolicy::Transliterate`] removes them.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{RussianMetaphone, PhonicsEncoder};
+///
+/// let enc = RussianMetaphone::new();
+/// assert_eq!(enc.encode("Иванов").unwrap(), "ИВАН4");
+/// ```
+pub struct RussianMetaphone {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The key is truncated to `max_code_len` characters.  If `max_code_len` is zero, the key is
+    /// not truncated.  The default value for `max_code_len` is in
+    /// [`RussianMetaphone::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`RussianMetaphone::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for RussianMetaphone {
+    fn new() -> RussianMetaphone {
+        RussianMetaphone {
+            special_characters_re: Regex::new(r"[^А-ЯЁ]").unwrap(),
+            white_space_re: Regex::new(r"[^А-ЯЁ]*").unwrap(),
+            max_code_len: RussianMetaphone::MAX_CODE_LEN_DEFAULT,
+            policy: RussianMetaphone::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let mut return_string = self
+            .white_space_re
+            .replace_all(&return_string, "")
+            .to_string();
+
+        // Compress the ending, if it is a known one
+        if let Some((ending, symbol)) = ENDINGS
+            .iter()
+            .find(|(ending, _)| return_string.ends_with(ending))
+        {
+            return_string.truncate(return_string.len() - ending.len());
+            return_string.push_str(symbol);
+        }
+
+        // Reduce vowels and simplify consonants
+        let return_string = utils::transliterate(&return_string, VOWELS);
+        let return_string = utils::transliterate(&return_string, CONSONANTS);
+
+        // Final and regressive devoicing
+        let return_string = utils::devoice_obstruents(&return_string, "БВГДЖЗ", "ПФКТШС");
+
+        // Remove duplicate consecutive characters
+        let mut return_string = utils::remove_duplicate_characters(&return_string);
+
+        // Truncate to requested length
+        if self.max_code_len > 0 {
+            return_string = utils::truncate_characters(&return_string, self.max_code_len);
+        }
+
+        Ok(return_string)
+    }
+}
+
+impl RussianMetaphone {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 0;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for RussianMetaphone {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for RussianMetaphone {
+    fn algorithm(&self) -> &str {
+        "russian_metaphone"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PhonicsEncoder, RussianMetaphone};
+
+    #[test]
+    fn test_russian_metaphone_default_max_code_len() {
+        let e = RussianMetaphone::new();
+
+        assert_eq!(e.max_code_len, RussianMetaphone::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_russian_metaphone_default_policy() {
+        let e = RussianMetaphone::new();
+
+        assert_eq!(e.policy, RussianMetaphone::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,201 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::utils::{Position, Rule, RuleSet};
+use crate::CharacterPolicy;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// Silent and respelled letters, applied in order before coding.
+const RULES: &[Rule] = &[
+    Rule::new("KN", "N").at(Position::Initial),
+    Rule::new("GN", "N").at(Position::Initial),
+    Rule::new("PN", "N").at(Position::Initial),
+    Rule::new("AC", "C").at(Position::Initial),
+    Rule::new("WR", "R").at(Position::Initial),
+    Rule::new("X", "S").at(Position::Initial),
+    Rule::new("WH", "W").at(Position::Initial),
+    Rule::new("DGE", "JE"),
+    Rule::new("DGI", "JI"),
+    Rule::new("GH", ""),
+];
+
+/// The SoundD procedure.
+///
+/// SoundD is a variant of Soundex that does not retain the first letter of
+/// the name, and instead codes every letter.  Before coding, the silent
+/// first letter of an initial "KN", "GN", "PN", "AC", or "WR" is dropped, an
+/// initial "X" becomes "S", and an initial "WH" becomes "W".  Then "DGE" and
+/// "DGI" are coded as a "J", and "GH" is silent.  The remaining letters are
+/// coded as in Soundex, with vowels, "H", "W", and "Y" separating
+/// consonants with the same code before being removed.  The code is
+/// zero-padded and truncated to `max_code_len` digits.
+///
+/// SoundD is only defined for inputs over the standard English alphabet,
+/// _i.e._, "A-Z".  Other characters are handled according to `policy`.
+///
+/// # References
+///
+/// Cihan Varol and Coşkun Bayrak. "Hybrid Matching Algorithm for Personal
+/// Names." _Journal of Data and Information Quality_ 3, no. 4 (2012).
+///
+/// # Example
+///
+/// ```
+/// use phonics::{PhonicsEncoder, SoundD};
+///
+/// let enc = SoundD::new();
+/// assert_eq!(enc.encode("Knight").unwrap(), "5300");
+/// ```
+pub struct SoundD {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The rewrite rules are compiled at instance instantiation to speed execution at run
+    /// time.
+    rules: RuleSet,
+
+    /// The SoundD specification has a standard maximum length.  However, it may be reasonable to
+    /// use either a shorter or longer length, depending on the application.  The default value
+    /// for `max_code_len` is in [`SoundD::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`SoundD::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for SoundD {
+    fn new() -> SoundD {
+        SoundD {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            rules: RuleSet::new(RULES, "AEIOU"),
+            max_code_len: SoundD::MAX_CODE_LEN_DEFAULT,
+            policy: SoundD::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Check if the return string is empty yet
+        if return_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        // Drop silent initial letters, code DGE and DGI as J, and drop GH
+        let return_string = self.rules.apply(&return_string);
+
+        // A, E, I, O, U, Y, H, W -> 0; B, F, P, V -> 1;
+        // C, G, J, K, Q, S, X, Z -> 2; D, T -> 3; L -> 4; M, N -> 5; R -> 6
+        let codes = utils::transform_characters(&return_string, "AEIOUYHW", "0");
+        let codes = utils::transform_characters(&codes, "BFPV", "1");
+        let codes = utils::transform_characters(&codes, "CGJKQSXZ", "2");
+        let codes = utils::transform_characters(&codes, "DT", "3");
+        let codes = utils::transform_characters(&codes, "L", "4");
+        let codes = utils::transform_characters(&codes, "MN", "5");
+        let codes = utils::transform_characters(&codes, "R", "6");
+
+        // Remove duplicate consecutive codes, then the separating zeros
+        let mut return_string = utils::remove_duplicate_characters(&codes).replace('0', "");
+
+        // Zero-pad and truncate to requested length
+        for _ in 0..self.max_code_len {
+            return_string.push('0');
+        }
+        let return_string = return_string.as_str()[0..self.max_code_len].to_string();
+
+        Ok(return_string)
+    }
+}
+
+impl SoundD {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 4;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+}
+
+impl Configurable for SoundD {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if options.max_code_len == Some(0) {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "SoundD codes are zero-padded and need a max_code_len of at least 1",
+            )));
+        }
+
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaggedEncoder for SoundD {
+    fn algorithm(&self) -> &str {
+        "sound_d"
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PhonicsEncoder, SoundD};
+
+    #[test]
+    fn test_sound_d_default_max_code_len() {
+        let e = SoundD::new();
+
+        assert_eq!(e.max_code_len, SoundD::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_sound_d_default_policy() {
+        let e = SoundD::new();
+
+        assert_eq!(e.policy, SoundD::POLICY_DEFAULT);
+    }
+}
@@ -0,0 +1,368 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use regex::Regex;
+
+use crate::utils;
+use crate::CharacterPolicy;
+use crate::CodeSet;
+use crate::Configurable;
+use crate::EncoderOptions;
+use crate::PackedEncoder;
+use crate::PhonicsEncoder;
+use crate::PhonicsError;
+use crate::TaggedEncoder;
+
+/// The surname prefixes that the Census codes both with and without.
+const PREFIXES: &[&str] = &["CON", "VAN", "DE", "DI", "LA", "LE"];
+
+/// The characters that may separate a prefix from the rest of the surname.
+const SEPARATORS: &[char] = &[' ', '\'', '’', '-'];
+
+/// The rules followed by a [`Soundex`] encoder.
+///
+/// The variants differ in whether vowels and the letters "H" and "W"
+/// separate consonants with the same code, and in the length of the code.
+/// In every variant, the first letter is retained and its code suppresses
+/// an identical code immediately following it.
+///
+/// The `SOUNDEX()` function of Oracle Database documents the same rules as
+/// [`SoundexVariant::Census`], so it has no variant of its own, and the
+/// registry name "soundex_oracle" returns a Census encoder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundexVariant {
+    /// American Soundex, as used by the U.S. Census Bureau.  Vowels separate
+    /// consonants with the same code, but "H" and "W" do not, so "Ashcraft"
+    /// is "A261".
+    Census,
+
+    /// The `SOUNDEX()` function of SQL Server at database compatibility
+    /// levels below 110.  Vowels, "H", and "W" all separate consonants with
+    /// the same code, so "Ashcraft" is "A226".  At compatibility level 110
+    /// and above, SQL Server follows the [`SoundexVariant::Census`] rules.
+    SqlServer,
+
+    /// The `SOUNDEX()` function of MySQL, which removes vowels before
+    /// collapsing repeated codes, so that vowels do not separate consonants
+    /// with the same code and "Tymczak" is "T520".  The code is not
+    /// truncated.
+    MySql,
+}
+
+/// The Soundex family of procedures.
+///
+/// Soundex retains the first letter of a name and codes the remaining
+/// consonants into six classes: B, F, P, V -> 1; C, G, J, K, Q, S, X, Z -> 2;
+/// D, T -> 3; L -> 4; M, N -> 5; and R -> 6.  Vowels, "H", "W", and "Y" are
+/// not coded, and consonants with the same code are collapsed.  Databases
+/// disagree on which letters separate repeated codes and on the length of
+/// the result, so the rules to follow are chosen with `variant`.  See
+/// [`SoundexVariant`] for the differences.
+///
+/// The Census also codes a surname with a prefix, such as "Van", "Con", "De",
+/// "Di", "La", or "Le", both with and without it.  The prefix is recognized
+/// where it is followed by a space, apostrophe, or hyphen, or where it ends
+/// in a lowercase letter followed by a capital letter, as in "Van Dyke",
+/// "La Rosa", and "DeLuca", so that "LANE" and "DEAN" have no prefix.  The
+/// code with the prefix is the primary code, and the code without it is an
+/// alternate code returned by [`PhonicsEncoder::encode_all`].  This is done
+/// in every variant, since the databases only return the primary code.
+///
+/// Soundex is only defined for inputs over the standard English alphabet,
+/// _i.e._, "A-Z".  Other characters are handled according to `policy`.
+///
+/// # References
+///
+/// Donald E. Knuth. _The Art of Computer Programming, Volume 3: Sorting and
+/// Searching._ Addison-Wesley, Reading, Massachusetts, 1973.
+///
+/// # Example
+///
+/// ```
+/// use phonics::{PhonicsEncoder, Soundex, SoundexVariant};
+///
+/// let enc = Soundex::new();
+/// assert_eq!(enc.encode("Ashcraft").unwrap(), "A261");
+///
+/// let enc = Soundex::with_variant(SoundexVariant::SqlServer);
+/// assert_eq!(enc.encode("Ashcraft").unwrap(), "A226");
+/// ```
+pub struct Soundex {
+    /// The special characters check regular expression is precompiled at instance instantiation
+    /// to speed execution at run time.
+    special_characters_re: Regex,
+
+    /// The white space removal regular expression is precompiled at instance instantiation to
+    /// speed execution at run time.
+    white_space_re: Regex,
+
+    /// The rules to follow.  The default value for `variant` is in
+    /// [`Soundex::VARIANT_DEFAULT`].
+    pub variant: SoundexVariant,
+
+    /// The code is zero-padded and truncated to `max_code_len` characters.  If `max_code_len`
+    /// is zero, the code is zero-padded to four characters but not truncated.  The default
+    /// value for `max_code_len` is in [`Soundex::MAX_CODE_LEN_DEFAULT`].
+    pub max_code_len: usize,
+
+    /// The handling of characters the encoder cannot process, as described in
+    /// [`CharacterPolicy`].  The default value is [`Soundex::POLICY_DEFAULT`].
+    pub policy: CharacterPolicy,
+}
+
+impl PhonicsEncoder for Soundex {
+    fn new() -> Soundex {
+        Soundex {
+            special_characters_re: Regex::new(r"[^A-Z]").unwrap(),
+            white_space_re: Regex::new(r"[^A-Z]*").unwrap(),
+            variant: Soundex::VARIANT_DEFAULT,
+            max_code_len: Soundex::MAX_CODE_LEN_DEFAULT,
+            policy: Soundex::POLICY_DEFAULT,
+        }
+    }
+
+    fn encode(&self, source_string: &str) -> Result<String, PhonicsError> {
+        // First, apply the character policy and uppercase it
+        let return_string =
+            utils::apply_policy(source_string, &self.special_characters_re, self.policy)?;
+        let return_string = return_string.to_uppercase();
+        let return_string = self.white_space_re.replace_all(&return_string, "");
+
+        // Check if the return string is empty yet
+        if return_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        // A, E, I, O, U, Y -> 0; H, W -> 9; B, F, P, V -> 1;
+        // C, G, J, K, Q, S, X, Z -> 2; D, T -> 3; L -> 4; M, N -> 5; R -> 6
+        let codes = utils::transform_characters(&return_string, "AEIOUY", "0");
+        let codes = utils::transform_characters(&codes, "HW", "9");
+        let codes = utils::transform_characters(&codes, "BFPV", "1");
+        let codes = utils::transform_characters(&codes, "CGJKQSXZ", "2");
+        let codes = utils::transform_characters(&codes, "DT", "3");
+        let codes = utils::transform_characters(&codes, "L", "4");
+        let codes = utils::transform_characters(&codes, "MN", "5");
+        let codes = utils::transform_characters(&codes, "R", "6");
+
+        // Vowels, and H and W, reset the last code only if they separate
+        let vowels_separate = self.variant != SoundexVariant::MySql;
+        let hw_separate = self.variant == SoundexVariant::SqlServer;
+
+        // First character of key = first character of name
+        let first_char = return_string.chars().next().unwrap();
+        let mut codes = codes.chars();
+        let mut last_code = codes.next().unwrap();
+        let mut return_string = first_char.to_string();
+
+        for code in codes {
+            match code {
+                '0' if vowels_separate => last_code = code,
+                '9' if hw_separate => last_code = code,
+                '0' | '9' => {}
+                _ => {
+                    if code != last_code {
+                        return_string.push(code);
+                    }
+                    last_code = code;
+                }
+            }
+        }
+
+        // Zero-pad and truncate to requested length
+        let code_len = if self.max_code_len == 0 {
+            return_string.len().max(4)
+        } else {
+            self.max_code_len
+        };
+        for _ in 1..code_len {
+            return_string.push('0');
+        }
+        let return_string = return_string.as_str()[0..code_len].to_string();
+
+        Ok(return_string)
+    }
+
+    fn encode_all(&self, source_string: &str) -> Result<CodeSet, PhonicsError> {
+        let primary = self.encode(source_string)?;
+        let alternates = match strip_prefix(source_string) {
+            Some(rest) => vec![self.encode(rest)?],
+            None => Vec::new(),
+        };
+
+        Ok(CodeSet::with_alternates(primary, alternates))
+    }
+}
+
+/// Return the surname given in `name` without its prefix, if it has one of
+/// [`PREFIXES`] followed by a separator, or ending in a lowercase letter
+/// followed by a capital letter, as in "VanDyke".  A name in capitals, such
+/// as "LANE", has no prefix unless it is separated.
+fn strip_prefix(name: &str) -> Option<&str> {
+    let name = name.trim_start();
+
+    for prefix in PREFIXES {
+        let head = match name.get(..prefix.len()) {
+            Some(head) if head.eq_ignore_ascii_case(prefix) => head,
+            _ => continue,
+        };
+        let rest = &name[head.len()..];
+        let separated = match rest.chars().next() {
+            Some(c) => {
+                SEPARATORS.contains(&c)
+                    || (head.ends_with(|h: char| h.is_lowercase()) && c.is_uppercase())
+            }
+            None => false,
+        };
+        let rest = rest.trim_start_matches(SEPARATORS);
+        if separated && rest.chars().any(char::is_alphabetic) {
+            return Some(rest);
+        }
+    }
+
+    None
+}
+
+impl Soundex {
+    /// The default value of the maximum allowable return length.
+    pub const MAX_CODE_LEN_DEFAULT: usize = 4;
+
+    /// The default value on the handling of special characters.
+    pub const POLICY_DEFAULT: CharacterPolicy = CharacterPolicy::Drop;
+
+    /// The default value of the rules to follow.
+    pub const VARIANT_DEFAULT: SoundexVariant = SoundexVariant::Census;
+
+    /// Return a new encoder following the rules of `variant`.
+    ///
+    /// The maximum length is set to the length that `variant` produces,
+    /// which is unlimited for [`SoundexVariant::MySql`] and four otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonics::{PhonicsEncoder, Soundex, SoundexVariant};
+    ///
+    /// let enc = Soundex::with_variant(SoundexVariant::MySql);
+    /// assert_eq!(enc.encode("Quadratically").unwrap(), "Q36324");
+    /// ```
+    pub fn with_variant(variant: SoundexVariant) -> Soundex {
+        let mut soundex = Soundex::new();
+
+        soundex.variant = variant;
+        if variant == SoundexVariant::MySql {
+            soundex.max_code_len = 0;
+        }
+
+        soundex
+    }
+}
+
+impl Configurable for Soundex {
+    fn configure(&mut self, options: &EncoderOptions) -> Result<(), PhonicsError> {
+        if let Some(max_code_len) = options.max_code_len {
+            self.max_code_len = max_code_len;
+        }
+        if let Some(policy) = options.policy {
+            self.policy = policy;
+        }
+
+        Ok(())
+    }
+}
+
+impl PackedEncoder for Soundex {
+    /// Return a letter followed by `max_code_len - 1` digits from "0" to "6".
+    fn key_alphabets(&self) -> Result<Vec<&str>, PhonicsError> {
+        if self.max_code_len == 0 {
+            return Err(PhonicsError::InvalidConfiguration(String::from(
+                "Soundex codes have no fixed length to pack with a max_code_len of 0",
+            )));
+        }
+
+        let mut alphabets = vec!["ABCDEFGHIJKLMNOPQRSTUVWXYZ"];
+        alphabets.resize(self.max_code_len, "0123456");
+
+        Ok(alphabets)
+    }
+}
+
+impl TaggedEncoder for Soundex {
+    fn algorithm(&self) -> &str {
+        match self.variant {
+            SoundexVariant::Census => "soundex",
+            SoundexVariant::SqlServer => "soundex_sql_server",
+            SoundexVariant::MySql => "soundex_mysql",
+        }
+    }
+
+    fn max_code_len(&self) -> Option<usize> {
+        Some(self.max_code_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PhonicsEncoder, Soundex, SoundexVariant};
+
+    #[test]
+    fn test_soundex_default_max_code_len() {
+        let e = Soundex::new();
+
+        assert_eq!(e.max_code_len, Soundex::MAX_CODE_LEN_DEFAULT);
+    }
+
+    #[test]
+    fn test_soundex_default_policy() {
+        let e = Soundex::new();
+
+        assert_eq!(e.policy, Soundex::POLICY_DEFAULT);
+    }
+
+    #[test]
+    fn test_soundex_default_variant() {
+        let e = Soundex::new();
+
+        assert_eq!(e.variant, Soundex::VARIANT_DEFAULT);
+    }
+
+    #[test]
+    fn test_soundex_with_variant() {
+        let expected = [
+            (SoundexVariant::Census, "T522", "A261", "P236"),
+            (SoundexVariant::SqlServer, "T522", "A226", "P236"),
+            (SoundexVariant::MySql, "T520", "A2613", "P236"),
+        ];
+
+        for (v, tymczak, ashcraft, pfister) in &expected {
+            let e = Soundex::with_variant(*v);
+            assert_eq!(e.variant, *v);
+            assert_eq!(e.encode("Tymczak").unwrap(), *tymczak);
+            assert_eq!(e.encode("Ashcraft").unwrap(), *ashcraft);
+            assert_eq!(e.encode("Pfister").unwrap(), *pfister);
+        }
+
+        let e = Soundex::with_variant(SoundexVariant::Census);
+        assert_eq!(e.max_code_len, Soundex::MAX_CODE_LEN_DEFAULT);
+        let e = Soundex::with_variant(SoundexVariant::MySql);
+        assert_eq!(e.max_code_len, 0);
+    }
+}
@@ -20,6 +20,14 @@
  * SOFTWARE.
  */
 
+use regex::Regex;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::CharacterPolicy;
+use crate::PhonicsError;
+use crate::UnknownCharacter;
+
 /// Remove the first character a string
 ///
 /// This function removes the first character of a string and returns the
@@ -68,9 +76,413 @@ pub fn transform_characters(string: &str, src: &str, dst: &str) -> String {
     return_string
 }
 
+/// Transliterate letter groups
+///
+/// This function scans a string from left to right and, at each position,
+/// replaces the first entry of `table` whose source group matches.  Scanning
+/// resumes after the matched group, so replacements are never rescanned.
+/// Characters not matched by any entry are copied unchanged.  Because the
+/// first match wins, longer groups must precede shorter groups sharing a
+/// prefix, _e.g._, "SCH" before "SC" before "S".
+pub fn transliterate(string: &str, table: &[(&str, &str)]) -> String {
+    let mut return_string = String::new();
+    let mut rest = string;
+
+    while let Some(c) = rest.chars().next() {
+        match table
+            .iter()
+            .find(|(src, _)| !src.is_empty() && rest.starts_with(src))
+        {
+            Some((src, dst)) => {
+                return_string.push_str(dst);
+                rest = &rest[src.len()..];
+            }
+            None => {
+                return_string.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    return_string
+}
+
+/// Devoice obstruents
+///
+/// Many languages pronounce a voiced obstruent as its voiceless counterpart
+/// at the end of a word or before another voiceless obstruent.  The
+/// characters of `voiced` and `voiceless` are paired by position, and the
+/// string is processed from right to left so that whole clusters devoice.
+pub fn devoice_obstruents(string: &str, voiced: &str, voiceless: &str) -> String {
+    let mut chars: Vec<char> = string.chars().collect();
+    let mut next: Option<char> = None;
+
+    for c in chars.iter_mut().rev() {
+        if let Some(i) = voiced.chars().position(|v| v == *c) {
+            let devoice = match next {
+                Some(n) => voiceless.contains(n),
+                None => true,
+            };
+            if devoice {
+                *c = voiceless.chars().nth(i).unwrap();
+            }
+        }
+        next = Some(*c);
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Truncate a string to a maximum number of characters
+///
+/// This function returns at most `len` characters from the start of the
+/// string.  Unlike slicing, it is safe for strings containing multibyte
+/// characters.
+pub fn truncate_characters(string: &str, len: usize) -> String {
+    string.chars().take(len).collect()
+}
+
+/// Find characters an encoder cannot process
+///
+/// This function composes each character of `string` with the combining
+/// marks that follow it, as in Unicode Normalization Form C, uppercases it,
+/// and tests it against `re`, an encoder's special characters regular
+/// expression.  Every character that matches is reported in
+/// [`PhonicsError::UnknownCharactersFound`] with the byte and character
+/// offsets in `string` of the character it was composed from, so the error
+/// points into the caller's input rather than the composed or uppercased
+/// copy.
+pub fn check_characters(string: &str, re: &Regex) -> Result<(), PhonicsError> {
+    let mut buffer = [0; 4];
+    let mut unknown = Vec::new();
+    let mut chars = string.char_indices().enumerate().peekable();
+
+    while let Some((char_offset, (byte_offset, c))) = chars.next() {
+        // Gather the combining marks that follow the character
+        let mut cluster = c.to_string();
+        while let Some((_, (_, mark))) = chars.peek() {
+            if !is_combining_mark(*mark) {
+                break;
+            }
+            cluster.push(*mark);
+            chars.next();
+        }
+
+        for character in cluster.nfc() {
+            if character
+                .to_uppercase()
+                .any(|u| re.is_match(u.encode_utf8(&mut buffer)))
+            {
+                unknown.push(UnknownCharacter {
+                    character,
+                    byte_offset,
+                    char_offset,
+                });
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(PhonicsError::UnknownCharactersFound(unknown))
+    }
+}
+
+/// Letters that have no decomposition and their ASCII spellings.
+const ASCII_LETTERS: &[(char, &str)] = &[
+    ('ß', "ss"),
+    ('ẞ', "SS"),
+    ('æ', "ae"),
+    ('Æ', "AE"),
+    ('ð', "d"),
+    ('Ð', "D"),
+    ('đ', "d"),
+    ('Đ', "D"),
+    ('ħ', "h"),
+    ('Ħ', "H"),
+    ('ı', "i"),
+    ('ł', "l"),
+    ('Ł', "L"),
+    ('œ', "oe"),
+    ('Œ', "OE"),
+    ('ø', "o"),
+    ('Ø', "O"),
+    ('ŧ', "t"),
+    ('Ŧ', "T"),
+    ('þ', "th"),
+    ('Þ', "TH"),
+];
+
+/// Fold a string to ASCII.
+///
+/// The string is decomposed to Unicode Normalization Form KD, which splits
+/// accented letters into a base letter and combining marks and spells out
+/// compatibility characters, such as the ligature "ﬁ".  The combining marks
+/// are removed, and letters with no decomposition, such as "ß", "Æ", "Ø",
+/// "Ł", and "Þ", are spelled out from a table.  Case is kept, and other
+/// characters are passed through unchanged.
+///
+/// This is the folding used by [`CharacterPolicy::Transliterate`], which
+/// only folds the characters an encoder cannot process.  Folding a whole
+/// name first instead, for an encoder whose alphabet has accented letters,
+/// such as [`Polish`](crate::Polish), reads "Łódź" as "Lodz".
+///
+/// # Example
+///
+/// ```
+/// use phonics::fold_to_ascii;
+///
+/// assert_eq!(fold_to_ascii("Ñúñez"), "Nunez");
+/// assert_eq!(fold_to_ascii("Straße"), "Strasse");
+/// ```
+pub fn fold_to_ascii(string: &str) -> String {
+    let mut return_string = String::new();
+
+    for c in string.nfkd().filter(|c| !is_combining_mark(*c)) {
+        match ASCII_LETTERS.iter().find(|(letter, _)| *letter == c) {
+            Some((_, spelling)) => return_string.push_str(spelling),
+            None => return_string.push(c),
+        }
+    }
+
+    return_string
+}
+
+/// Apply a character policy
+///
+/// This function prepares `string` for an encoder whose special characters
+/// regular expression is `re`, according to `policy`.  The string is first
+/// composed to Unicode Normalization Form C, so that a letter written with
+/// combining marks is read as the precomposed letter of the encoder's
+/// alphabet.  Under [`CharacterPolicy::Reject`], any character the encoder
+/// cannot process is reported as by [`check_characters`].  Under
+/// [`CharacterPolicy::Transliterate`], each such character is uppercased
+/// and folded to ASCII with [`fold_to_ascii`].  Otherwise, the composed
+/// string is returned, and the encoder removes what it cannot process.
+pub fn apply_policy(
+    string: &str,
+    re: &Regex,
+    policy: CharacterPolicy,
+) -> Result<String, PhonicsError> {
+    if policy == CharacterPolicy::Reject {
+        check_characters(string, re)?;
+    }
+    let string: String = string.nfc().collect();
+
+    match policy {
+        CharacterPolicy::Reject | CharacterPolicy::Drop => Ok(string),
+        CharacterPolicy::Transliterate => {
+            let mut buffer = [0; 4];
+            let mut return_string = String::new();
+
+            for c in string.chars() {
+                if c.to_uppercase()
+                    .all(|u| !re.is_match(u.encode_utf8(&mut buffer)))
+                {
+                    return_string.push(c);
+                    continue;
+                }
+                return_string.push_str(&fold_to_ascii(&c.to_uppercase().to_string()));
+            }
+
+            Ok(return_string)
+        }
+    }
+}
+
+/// The context a rewrite rule requires beside its pattern
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Context {
+    /// Any character, or none.
+    Any,
+
+    /// A vowel of the rule set.
+    Vowel,
+
+    /// A letter that is not a vowel of the rule set.
+    Consonant,
+
+    /// The start or end of the string, or a character that is not a letter.
+    Boundary,
+
+    /// Any character other than the one given, or none.
+    Except(char),
+}
+
+/// The position in a word a rewrite rule's pattern must take
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Position {
+    /// Anywhere in the word.
+    Any,
+
+    /// At the start of the word.
+    Initial,
+
+    /// Neither at the start nor at the end of the word.
+    Medial,
+
+    /// At the end of the word.
+    Final,
+}
+
+/// A context-sensitive rewrite rule
+///
+/// This structure describes a rule replacing every `pattern` with
+/// `replacement` where the character before the pattern matches `left`,
+/// the character after it matches `right`, and the pattern is at
+/// `position` in its word.  Rules are written in tables with
+/// [`Rule::new`] and narrowed with [`Rule::after`], [`Rule::before`], and
+/// [`Rule::at`], and are compiled into a [`RuleSet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub pattern: &'a str,
+    pub replacement: &'a str,
+    pub left: Context,
+    pub right: Context,
+    pub position: Position,
+}
+
+impl<'a> Rule<'a> {
+    /// Return a rule replacing `pattern` with `replacement` in any context.
+    pub const fn new(pattern: &'a str, replacement: &'a str) -> Rule<'a> {
+        Rule {
+            pattern,
+            replacement,
+            left: Context::Any,
+            right: Context::Any,
+            position: Position::Any,
+        }
+    }
+
+    /// Return the rule, applying only after `left`.
+    pub const fn after(self, left: Context) -> Rule<'a> {
+        Rule { left, ..self }
+    }
+
+    /// Return the rule, applying only before `right`.
+    pub const fn before(self, right: Context) -> Rule<'a> {
+        Rule { right, ..self }
+    }
+
+    /// Return the rule, applying only at `position`.
+    pub const fn at(self, position: Position) -> Rule<'a> {
+        Rule { position, ..self }
+    }
+}
+
+/// A compiled [`Rule`].
+struct CompiledRule {
+    pattern: Vec<char>,
+    replacement: String,
+    left: Context,
+    right: Context,
+    position: Position,
+}
+
+/// Ordered context-sensitive rewrite rules
+///
+/// This structure holds a table of [`Rule`]s that are applied in order,
+/// each to the result of the one before.  Within a rule, the string is
+/// scanned from left to right and each match is replaced, so matches do
+/// not overlap, and the contexts are read from the string as it was before
+/// that rule.  The table is compiled once, when the rule set is created, so
+/// a rule set built at encoder instantiation is cheap to apply at run time.
+/// No regular expressions are involved, and the vowels are given with the
+/// rule set.
+pub struct RuleSet {
+    rules: Vec<CompiledRule>,
+    vowels: Vec<char>,
+}
+
+impl RuleSet {
+    /// Compile a table of rules, with `vowels` as the vowels for
+    /// [`Context::Vowel`] and [`Context::Consonant`].
+    pub fn new(rules: &[Rule], vowels: &str) -> RuleSet {
+        RuleSet {
+            rules: rules
+                .iter()
+                .map(|rule| {
+                    assert!(!rule.pattern.is_empty(), "a rule has an empty pattern");
+                    CompiledRule {
+                        pattern: rule.pattern.chars().collect(),
+                        replacement: rule.replacement.to_string(),
+                        left: rule.left,
+                        right: rule.right,
+                        position: rule.position,
+                    }
+                })
+                .collect(),
+            vowels: vowels.chars().collect(),
+        }
+    }
+
+    /// Apply each rule, in order, to `string`.
+    pub fn apply(&self, string: &str) -> String {
+        let mut chars: Vec<char> = string.chars().collect();
+
+        for rule in &self.rules {
+            let mut return_string = String::new();
+            let mut i = 0;
+
+            while i < chars.len() {
+                if self.matches(rule, &chars, i) {
+                    return_string.push_str(&rule.replacement);
+                    i += rule.pattern.len();
+                } else {
+                    return_string.push(chars[i]);
+                    i += 1;
+                }
+            }
+
+            chars = return_string.chars().collect();
+        }
+
+        chars.into_iter().collect()
+    }
+
+    /// Test whether `rule` matches `chars` at `i`.
+    fn matches(&self, rule: &CompiledRule, chars: &[char], i: usize) -> bool {
+        if !chars[i..].starts_with(&rule.pattern) {
+            return false;
+        }
+
+        let left = if i > 0 { Some(chars[i - 1]) } else { None };
+        let right = chars.get(i + rule.pattern.len()).copied();
+        let position = match rule.position {
+            Position::Any => true,
+            Position::Initial => self.is_context(Context::Boundary, left),
+            Position::Medial => {
+                !self.is_context(Context::Boundary, left)
+                    && !self.is_context(Context::Boundary, right)
+            }
+            Position::Final => self.is_context(Context::Boundary, right),
+        };
+
+        position && self.is_context(rule.left, left) && self.is_context(rule.right, right)
+    }
+
+    /// Test whether the character `c`, if any, is in `context`.
+    fn is_context(&self, context: Context, c: Option<char>) -> bool {
+        match (context, c) {
+            (Context::Any, _) => true,
+            (Context::Vowel, Some(c)) => self.vowels.contains(&c),
+            (Context::Consonant, Some(c)) => c.is_alphabetic() && !self.vowels.contains(&c),
+            (Context::Boundary, Some(c)) => !c.is_alphabetic(),
+            (Context::Boundary, None) => true,
+            (Context::Except(except), Some(c)) => c != except,
+            (Context::Except(_), None) => true,
+            (_, None) => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use regex::Regex;
+
     use crate::utils;
+    use crate::{CharacterPolicy, PhonicsError, UnknownCharacter};
 
     #[test]
     fn strip_first_char_works() {
@@ -105,4 +517,156 @@ mod tests {
         assert_eq!(utils::transform_characters("H", "F", "5"), "H");
         assert_eq!(utils::transform_characters("", "H", "5"), "");
     }
+
+    #[test]
+    fn transliterate_works() {
+        let table = [("SCH", "X"), ("SZ", "S"), ("S", "Z"), ("Ł", "L")];
+
+        assert_eq!(utils::transliterate("SCHASZA", &table), "XASA");
+        assert_eq!(utils::transliterate("SSS", &table), "ZZZ");
+        assert_eq!(utils::transliterate("ŁÓDŹ", &table), "LÓDŹ");
+        assert_eq!(utils::transliterate("", &table), "");
+        assert_eq!(utils::transliterate("ABC", &[]), "ABC");
+    }
+
+    #[test]
+    fn devoice_obstruents_works() {
+        assert_eq!(utils::devoice_obstruents("BOG", "BDGZ", "PTKS"), "BOK");
+        assert_eq!(utils::devoice_obstruents("ZBT", "BDGZ", "PTKS"), "SPT");
+        assert_eq!(utils::devoice_obstruents("BDA", "BDGZ", "PTKS"), "BDA");
+        assert_eq!(utils::devoice_obstruents("", "BDGZ", "PTKS"), "");
+    }
+
+    #[test]
+    fn truncate_characters_works() {
+        assert_eq!(utils::truncate_characters("Hilbert", 4), "Hilb");
+        assert_eq!(utils::truncate_characters("Łódź", 2), "Łó");
+        assert_eq!(utils::truncate_characters("H", 4), "H");
+        assert_eq!(utils::truncate_characters("", 4), "");
+    }
+
+    #[test]
+    fn check_characters_works() {
+        let re = Regex::new(r"[^A-Z]").unwrap();
+
+        assert!(utils::check_characters("Hilbert", &re).is_ok());
+        assert!(utils::check_characters("", &re).is_ok());
+        assert_eq!(
+            utils::check_characters("Mü ller", &re).unwrap_err(),
+            PhonicsError::UnknownCharactersFound(vec![
+                UnknownCharacter {
+                    character: 'ü',
+                    byte_offset: 1,
+                    char_offset: 1,
+                },
+                UnknownCharacter {
+                    character: ' ',
+                    byte_offset: 3,
+                    char_offset: 2,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn check_characters_composes() {
+        let re = Regex::new(r"[^A-ZÜ]").unwrap();
+
+        assert!(utils::check_characters("Mu\u{308}ller", &re).is_ok());
+        assert_eq!(
+            utils::check_characters("Mu\u{308}n\u{303}oz", &re).unwrap_err(),
+            PhonicsError::UnknownCharactersFound(vec![UnknownCharacter {
+                character: 'ñ',
+                byte_offset: 4,
+                char_offset: 3,
+            }])
+        );
+    }
+
+    #[test]
+    fn fold_to_ascii_works() {
+        assert_eq!(utils::fold_to_ascii("Ñúñez"), "Nunez");
+        assert_eq!(utils::fold_to_ascii("Straße"), "Strasse");
+        assert_eq!(utils::fold_to_ascii("STRASSE"), "STRASSE");
+        assert_eq!(utils::fold_to_ascii("Ærø"), "AEro");
+        assert_eq!(utils::fold_to_ascii("Łódź"), "Lodz");
+        assert_eq!(utils::fold_to_ascii("Þórr"), "THorr");
+        assert_eq!(utils::fold_to_ascii("Nu\u{301}n\u{303}ez"), "Nunez");
+        assert_eq!(utils::fold_to_ascii("ﬁnn"), "finn");
+        assert_eq!(utils::fold_to_ascii("O'Brien-Smith"), "O'Brien-Smith");
+        assert_eq!(utils::fold_to_ascii(""), "");
+    }
+
+    #[test]
+    fn apply_policy_works() {
+        let re = Regex::new(r"[^A-Z]").unwrap();
+
+        assert_eq!(
+            utils::apply_policy("Müller", &re, CharacterPolicy::Drop).unwrap(),
+            "Müller"
+        );
+        assert_eq!(
+            utils::apply_policy("Müller", &re, CharacterPolicy::Transliterate).unwrap(),
+            "MUller"
+        );
+        assert_eq!(
+            utils::apply_policy("Łaść-Øre", &re, CharacterPolicy::Transliterate).unwrap(),
+            "LaSC-Ore"
+        );
+        assert_eq!(
+            utils::apply_policy("Straße", &re, CharacterPolicy::Transliterate).unwrap(),
+            "Straße"
+        );
+        assert!(utils::apply_policy("Müller", &re, CharacterPolicy::Reject).is_err());
+        assert_eq!(
+            utils::apply_policy("Muller", &re, CharacterPolicy::Reject).unwrap(),
+            "Muller"
+        );
+
+        let re = Regex::new(r"[^A-ZÄÖÜ]").unwrap();
+        assert_eq!(
+            utils::apply_policy("Müller Ÿ", &re, CharacterPolicy::Transliterate).unwrap(),
+            "Müller Y"
+        );
+    }
+
+    #[test]
+    fn rule_set_works() {
+        use utils::{Context, Position, Rule};
+
+        let rules = utils::RuleSet::new(
+            &[
+                Rule::new("KN", "N").at(Position::Initial),
+                Rule::new("GH", "").at(Position::Final),
+                Rule::new("PH", "F").at(Position::Medial),
+                Rule::new("S", "Z")
+                    .after(Context::Vowel)
+                    .before(Context::Vowel),
+                Rule::new("C", "K").before(Context::Consonant),
+                Rule::new("E", "")
+                    .after(Context::Consonant)
+                    .before(Context::Boundary),
+            ],
+            "AEIOU",
+        );
+
+        assert_eq!(rules.apply("KNIGHT"), "NIGHT");
+        assert_eq!(rules.apply("ACKNOWLEDGE"), "AKKNOWLEDG");
+        assert_eq!(rules.apply("HIGH"), "HI");
+        assert_eq!(rules.apply("HIGH KNIGHT"), "HI NIGHT");
+        assert_eq!(rules.apply("SOPHIA"), "SOFIA");
+        assert_eq!(rules.apply("PHIL"), "PHIL");
+        assert_eq!(rules.apply("ROSA"), "ROZA");
+        assert_eq!(rules.apply("ROSSA"), "ROSSA");
+        assert_eq!(rules.apply("ASASA"), "AZAZA");
+        assert_eq!(rules.apply("CLARE"), "KLAR");
+        assert_eq!(rules.apply("CAROL"), "CAROL");
+        assert_eq!(rules.apply(""), "");
+
+        let rules =
+            utils::RuleSet::new(&[Rule::new("G", "K").after(Context::Except('N'))], "AEIOU");
+
+        assert_eq!(rules.apply("GANG"), "KANG");
+        assert_eq!(rules.apply("NGG"), "NGK");
+    }
 }
@@ -0,0 +1,112 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{AlphaSis, CharacterPolicy, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_alpha_sis_phonics() {
+    let e = Phonics::<AlphaSis>::new();
+
+    assert_eq!(e.encode("Christopher").unwrap(), "06401840000000");
+    assert_eq!(e.encode("Niall").unwrap(), "02500000000000");
+    assert_eq!(e.encode("Smith").unwrap(), "03100000000000");
+}
+
+#[test]
+fn test_alpha_sis_directly() {
+    let e = AlphaSis::new();
+
+    assert_eq!(e.encode("Christopher").unwrap(), "06401840000000");
+    assert_eq!(e.encode("Niall").unwrap(), "02500000000000");
+    assert_eq!(e.encode("Smith").unwrap(), "03100000000000");
+    assert_eq!(e.encode("Schmidt").unwrap(), "06310000000000");
+    assert_eq!(e.encode("Knight").unwrap(), "02710000000000");
+    assert_eq!(e.encode("Wright").unwrap(), "04710000000000");
+    assert_eq!(e.encode("Psmith").unwrap(), "03100000000000");
+    assert_eq!(e.encode("Adams").unwrap(), "13000000000000");
+    assert_eq!(e.encode("Euler").unwrap(), "15400000000000");
+    assert_eq!(e.encode("Jackson").unwrap(), "37020000000000");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+}
+
+#[test]
+fn test_alpha_sis_alternates() {
+    let e = AlphaSis::new();
+
+    assert_eq!(
+        e.encode_alternates("Christopher").unwrap(),
+        vec!["06401840000000", "07040184000000", "04018400000000"]
+    );
+    assert_eq!(
+        e.encode_alternates("Czerny").unwrap(),
+        vec!["07042000000000", "06420000000000", "04200000000000"]
+    );
+    assert_eq!(
+        e.encode_alternates("Jackson").unwrap(),
+        vec!["37020000000000", "36020000000000"]
+    );
+    assert_eq!(
+        e.encode_alternates("Niall").unwrap(),
+        vec!["02500000000000"]
+    );
+    assert!(e.encode_alternates("").unwrap().is_empty());
+
+    // Only the first letter group yields alternates
+    let long = "CH".repeat(13);
+    assert_eq!(
+        e.encode_alternates(&long).unwrap(),
+        vec!["06000000000000", "07060000000000"]
+    );
+    assert_eq!(
+        e.encode_alternates("Czech").unwrap(),
+        vec!["07060000000000", "06000000000000"]
+    );
+}
+
+#[test]
+fn test_alpha_sis_max_code_len() {
+    let mut e = AlphaSis::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Christopher").unwrap(), "0640");
+    assert_eq!(e.encode("Niall").unwrap(), "0250");
+    assert_eq!(
+        e.encode_alternates("Christopher").unwrap(),
+        vec!["0640", "0704", "0401"]
+    );
+
+    // Alternates equal after truncation are not repeated
+    e.max_code_len = 1;
+    assert_eq!(e.encode_alternates("Christopher").unwrap(), vec!["0"]);
+}
+
+#[test]
+fn test_alpha_sis_policy() {
+    let mut e = AlphaSis::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Schmidt").unwrap(), "06310000000000");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("O'Brien").is_err());
+    assert!(e.encode_alternates("Smith 2").is_err());
+}
@@ -0,0 +1,105 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{Arabic, CharacterPolicy, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_arabic_phonics() {
+    let e = Phonics::<Arabic>::new();
+
+    assert_eq!(e.encode("Mohammed").unwrap(), "MHMD");
+    assert_eq!(e.encode("محمد").unwrap(), "MHMD");
+    assert_eq!(e.encode("Abdul Rahman").unwrap(), "BDRHMN");
+}
+
+#[test]
+fn test_arabic_romanized() {
+    let e = Arabic::new();
+
+    assert_eq!(e.encode("Mohammed").unwrap(), "MHMD");
+    assert_eq!(e.encode("Muhammad").unwrap(), "MHMD");
+    assert_eq!(e.encode("Mohamad").unwrap(), "MHMD");
+    assert_eq!(e.encode("Abdul Rahman").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("Abd al-Rahman").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("Abdel Rahman").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("Abdurrahman").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("Abdullah").unwrap(), "BDL");
+    assert_eq!(e.encode("Abdulaziz").unwrap(), "BDZ");
+    assert_eq!(e.encode("Abd al-Aziz").unwrap(), "BDZ");
+    assert_eq!(e.encode("Yusuf").unwrap(), "YSF");
+    assert_eq!(e.encode("Youssef").unwrap(), "YSF");
+    assert_eq!(e.encode("Hussein").unwrap(), "HSN");
+    assert_eq!(e.encode("Husayn").unwrap(), "HSN");
+    assert_eq!(e.encode("Ali").unwrap(), "L");
+    assert_eq!(e.encode("Aly").unwrap(), "L");
+    assert_eq!(e.encode("Fatimah").unwrap(), "FTM");
+    assert_eq!(e.encode("Fatma").unwrap(), "FTM");
+    assert_eq!(e.encode("Othman").unwrap(), "SMN");
+    assert_eq!(e.encode("Usman").unwrap(), "SMN");
+    assert_eq!(e.encode("Khalid").unwrap(), "XLD");
+    assert_eq!(e.encode("Nasr ad-Din").unwrap(), "NSRDN");
+    assert_eq!(e.encode("El-Sayed").unwrap(), "SD");
+    assert_eq!(e.encode("Sa'id").unwrap(), "SD");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_arabic_script() {
+    let e = Arabic::new();
+
+    assert_eq!(e.encode("محمد").unwrap(), "MHMD");
+    assert_eq!(e.encode("مُحَمَّد").unwrap(), "MHMD");
+    assert_eq!(e.encode("عبد الرحمن").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("عبدالرحمن").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("عبدالله").unwrap(), "BDL");
+    assert_eq!(e.encode("عبد العزيز").unwrap(), "BDZ");
+    assert_eq!(e.encode("يوسف").unwrap(), "YSF");
+    assert_eq!(e.encode("حسين").unwrap(), "HSN");
+    assert_eq!(e.encode("علي").unwrap(), "L");
+    assert_eq!(e.encode("فاطمة").unwrap(), "FTM");
+    assert_eq!(e.encode("عثمان").unwrap(), "SMN");
+    assert_eq!(e.encode("خالد").unwrap(), "XLD");
+    assert_eq!(e.encode("نصر الدين").unwrap(), "NSRDN");
+}
+
+#[test]
+fn test_arabic_max_code_len() {
+    let mut e = Arabic::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Abdul Rahman").unwrap(), "BDRH");
+    assert_eq!(e.encode("عبد الرحمن").unwrap(), "BDRH");
+    assert_eq!(e.encode("Ali").unwrap(), "L");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_arabic_policy() {
+    let mut e = Arabic::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Abd al-Rahman").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("عبد الرحمن").unwrap(), "BDRHMN");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Mohammed 2").is_err());
+    assert!(e.encode("Müller").is_err());
+}
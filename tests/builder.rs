@@ -0,0 +1,102 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{
+    AlphaSis, CharacterPolicy, Configurable, Davidson, EncoderOptions, Lein, Phonics,
+    PhonicsEncoder, PhonicsError, Polish, Soundex,
+};
+
+#[test]
+fn test_builder_defaults() {
+    let e = Phonics::<Lein>::builder().build().unwrap();
+
+    assert_eq!(e.encode("Hilbert").unwrap(), "H343");
+    assert_eq!(e.encode("Euler3.1415").unwrap(), "E330");
+}
+
+#[test]
+fn test_builder_max_code_len() {
+    let e = Phonics::<Lein>::builder().max_code_len(6).build().unwrap();
+
+    assert_eq!(e.encode("Hilbert").unwrap(), "H34310");
+
+    let e = Phonics::<Soundex>::builder()
+        .max_code_len(0)
+        .build()
+        .unwrap();
+    assert_eq!(e.encode("Washington").unwrap(), "W25235");
+
+    let e = Phonics::<Polish>::builder()
+        .max_code_len(3)
+        .build()
+        .unwrap();
+    assert_eq!(e.encode("Szymański").unwrap(), "XIM");
+}
+
+#[test]
+fn test_builder_policy() {
+    let e = Phonics::<Lein>::builder()
+        .policy(CharacterPolicy::Reject)
+        .build()
+        .unwrap();
+
+    assert_eq!(e.encode("Hilbert").unwrap(), "H343");
+    assert!(e.encode("Euler3.1415").is_err());
+
+    let e = Phonics::<Davidson>::builder()
+        .policy(CharacterPolicy::Reject)
+        .build()
+        .unwrap();
+    assert!(e.encode("O'Brien, Mary").is_err());
+}
+
+#[test]
+fn test_builder_invalid() {
+    match Phonics::<Lein>::builder().max_code_len(0).build() {
+        Err(PhonicsError::InvalidConfiguration(message)) => {
+            assert!(message.contains("max_code_len"))
+        }
+        _ => panic!("a max_code_len of zero was accepted"),
+    }
+
+    assert!(Phonics::<AlphaSis>::builder()
+        .max_code_len(0)
+        .build()
+        .is_err());
+    assert!(Phonics::<Davidson>::builder()
+        .max_code_len(4)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_configure_unchanged_on_error() {
+    let mut e = Lein::new();
+    let options = EncoderOptions {
+        max_code_len: Some(0),
+        policy: Some(CharacterPolicy::Reject),
+    };
+
+    assert!(e.configure(&options).is_err());
+    assert_eq!(e.max_code_len, Lein::MAX_CODE_LEN_DEFAULT);
+    assert_eq!(e.policy, Lein::POLICY_DEFAULT);
+}
@@ -0,0 +1,107 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Chinese, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_chinese_phonics() {
+    let e = Phonics::<Chinese>::new();
+
+    assert_eq!(e.encode("Xu").unwrap(), "XU");
+    assert_eq!(e.encode("Hsu").unwrap(), "XU");
+    assert_eq!(e.encode("Zhang").unwrap(), "JANG");
+}
+
+#[test]
+fn test_chinese_directly() {
+    let e = Chinese::new();
+
+    assert_eq!(e.encode("Xu").unwrap(), "XU");
+    assert_eq!(e.encode("Xǔ").unwrap(), "XU");
+    assert_eq!(e.encode("Xu3").unwrap(), "XU");
+    assert_eq!(e.encode("Hsu").unwrap(), "XU");
+    assert_eq!(e.encode("Hsü").unwrap(), "XU");
+    assert_eq!(e.encode("Tsui").unwrap(), "XU");
+    assert_eq!(e.encode("Zhang").unwrap(), "JANG");
+    assert_eq!(e.encode("Zhāng").unwrap(), "JANG");
+    assert_eq!(e.encode("Chang").unwrap(), "JANG");
+    assert_eq!(e.encode("Cheung").unwrap(), "JANG");
+    assert_eq!(e.encode("Jen").unwrap(), "REN");
+    assert_eq!(e.encode("Ren").unwrap(), "REN");
+    assert_eq!(e.encode("Jung").unwrap(), "RONG");
+    assert_eq!(e.encode("Rong").unwrap(), "RONG");
+    assert_eq!(e.encode("Jih").unwrap(), "RI");
+    assert_eq!(e.encode("Jiang").unwrap(), "JIANG");
+    assert_eq!(e.encode("Jun").unwrap(), "JUN");
+    assert_eq!(e.encode("Chen").unwrap(), "JEN");
+    assert_eq!(e.encode("Ch'en").unwrap(), "JEN");
+    assert_eq!(e.encode("Chan").unwrap(), "JEN");
+    assert_eq!(e.encode("Guo").unwrap(), "GUO");
+    assert_eq!(e.encode("Kuo").unwrap(), "GUO");
+    assert_eq!(e.encode("Kwok").unwrap(), "GUO");
+    assert_eq!(e.encode("Xie").unwrap(), "XIE");
+    assert_eq!(e.encode("Hsieh").unwrap(), "XIE");
+    assert_eq!(e.encode("Shi").unwrap(), "SHI");
+    assert_eq!(e.encode("Shih").unwrap(), "SHI");
+    assert_eq!(e.encode("Shihlin").unwrap(), "SHILIN");
+    assert_eq!(e.encode("Yehliu").unwrap(), "YELIU");
+    assert_eq!(e.encode("Kong").unwrap(), "GONG");
+    assert_eq!(e.encode("K'ung").unwrap(), "GONG");
+    assert_eq!(e.encode("Lǚ").unwrap(), "LU");
+    assert_eq!(e.encode("Lv").unwrap(), "LU");
+    assert_eq!(e.encode("Wong").unwrap(), "HUANG");
+    assert_eq!(e.encode("Ng").unwrap(), "WU");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_chinese_full_names() {
+    let e = Chinese::new();
+
+    assert_eq!(e.encode("Mao Zedong").unwrap(), "MAOZEDONG");
+    assert_eq!(e.encode("Mao Tse-tung").unwrap(), "MAOZEDONG");
+    assert_eq!(e.encode("Deng Xiaoping").unwrap(), "DENGXIAOBING");
+    assert_eq!(e.encode("Teng Hsiao-p'ing").unwrap(), "DENGXIAOBING");
+    assert_eq!(e.encode("Sima Qian").unwrap(), "SIMAJIAN");
+    assert_eq!(e.encode("Szu-ma Ch'ien").unwrap(), "SIMAJIAN");
+}
+
+#[test]
+fn test_chinese_max_code_len() {
+    let mut e = Chinese::new();
+
+    e.max_code_len = 3;
+    assert_eq!(e.encode("Zhang").unwrap(), "JAN");
+    assert_eq!(e.encode("Hsu").unwrap(), "XU");
+}
+
+#[test]
+fn test_chinese_policy() {
+    let mut e = Chinese::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Teng Hsiao-p'ing").unwrap(), "DENGXIAOBING");
+    assert_eq!(e.encode("Zhang1").unwrap(), "JANG");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Zhang9").is_err());
+    assert!(e.encode("张").is_err());
+}
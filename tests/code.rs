@@ -0,0 +1,110 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use std::collections::BTreeSet;
+
+use phonics::{
+    CustomEncoder, Davidson, Lein, PhoneticCode, Phonics, PhonicsEncoder, PhonicsError, Soundex,
+    SoundexVariant, TaggedEncoder,
+};
+
+#[test]
+fn test_code_phonics() {
+    let e = Phonics::<Lein>::builder().max_code_len(6).build().unwrap();
+    let code = e.encode_code("Hilbert").unwrap();
+
+    assert_eq!(code.algorithm(), "lein");
+    assert_eq!(code.max_code_len(), Some(6));
+    assert_eq!(code.code(), "H34310");
+    assert_eq!(code.to_string(), "lein/6:H34310");
+}
+
+#[test]
+fn test_code_directly() {
+    let e = Lein::new();
+
+    assert_eq!(e.encode_code("Hilbert").unwrap().to_string(), "lein/4:H343");
+    assert_eq!(e.encode_code(""), Err(PhonicsError::EmptyInput));
+    assert_eq!(
+        Davidson::new().encode_code("Howard, James").unwrap(),
+        "davidson:HRD J".parse::<PhoneticCode>().unwrap()
+    );
+}
+
+#[test]
+fn test_code_compare() {
+    let lein = Lein::new();
+    let mut lein6 = Lein::new();
+    lein6.max_code_len = 6;
+    let soundex = Soundex::new();
+    let sql_server = Soundex::with_variant(SoundexVariant::SqlServer);
+
+    let knuth = lein.encode_code("Knuth").unwrap();
+    assert!(knuth.matches(&lein.encode_code("Kant").unwrap()).unwrap());
+    assert!(!knuth
+        .matches(&lein.encode_code("Hilbert").unwrap())
+        .unwrap());
+    assert!(knuth
+        .try_cmp(&lein.encode_code("Hilbert").unwrap())
+        .unwrap()
+        .is_gt());
+
+    // Equal strings from different encoders or settings do not compare
+    let robert = soundex.encode_code("Robert").unwrap();
+    let rupert = sql_server.encode_code("Rupert").unwrap();
+    assert_eq!(robert.code(), rupert.code());
+    assert_ne!(robert, rupert);
+    assert!(matches!(
+        robert.matches(&rupert),
+        Err(PhonicsError::IncompatibleCodes(_))
+    ));
+    assert!(knuth.matches(&lein6.encode_code("Knuth").unwrap()).is_err());
+    assert_eq!(
+        knuth.matches(&robert).unwrap_err().to_string(),
+        "incompatible codes: lein/4 and soundex/4"
+    );
+}
+
+#[test]
+fn test_code_ordered() {
+    let e = Lein::new();
+    let codes: BTreeSet<PhoneticCode> = ["Knuth", "Hilbert", "Kant", "Euler"]
+        .iter()
+        .map(|name| e.encode_code(name).unwrap())
+        .collect();
+
+    assert_eq!(
+        codes.iter().map(|c| c.code()).collect::<Vec<_>>(),
+        ["E330", "H343", "K210"]
+    );
+}
+
+#[test]
+fn test_code_custom() {
+    let e = CustomEncoder::from_toml("name = \"upper\"\nmax_code_len = 3").unwrap();
+
+    assert_eq!(e.encode_code("Knuth").unwrap().to_string(), "upper/3:KNU");
+    assert!(CustomEncoder::from_toml("name = \"a:b\"").is_err());
+
+    // A custom encoder cannot pass its codes off as those of a built-in one
+    assert!(CustomEncoder::from_toml("name = \"lein\"\nmax_code_len = 4").is_err());
+}
@@ -0,0 +1,92 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{encoder_by_name, AlphaSis, CodeSet, EncoderOptions, Lein, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_codes_default() {
+    let e = Lein::new();
+    let codes = e.encode_all("Hilbert").unwrap();
+
+    assert_eq!(codes.primary(), "H343");
+    assert!(codes.alternates().is_empty());
+    assert_eq!(codes, CodeSet::new(String::from("H343")));
+    assert!(e.sounds_alike("Knuth", "Kant").unwrap());
+    assert!(!e.sounds_alike("Hilbert", "Knuth").unwrap());
+}
+
+#[test]
+fn test_codes_alternates() {
+    let e = AlphaSis::new();
+    let codes = e.encode_all("Christopher").unwrap();
+
+    assert_eq!(codes.primary(), e.encode("Christopher").unwrap());
+    assert_eq!(
+        codes.iter().collect::<Vec<_>>(),
+        e.encode_alternates("Christopher").unwrap()
+    );
+    assert!(codes.contains("07040184000000"));
+    assert!(!codes.contains("07401840000000"));
+    assert_eq!(e.encode_all("").unwrap(), CodeSet::new(String::new()));
+}
+
+#[test]
+fn test_codes_phonics() {
+    let e = Phonics::<AlphaSis>::new();
+
+    assert_eq!(
+        e.encode_all("Jackson").unwrap().alternates(),
+        ["36020000000000"]
+    );
+    assert!(e.sounds_alike("Czerny", "Cherny").unwrap());
+
+    let e = encoder_by_name("alpha_sis", &EncoderOptions::default()).unwrap();
+    assert_eq!(
+        e.encode_all("Jackson").unwrap().alternates(),
+        ["36020000000000"]
+    );
+    assert!(e.sounds_alike("Czerny", "Tserny").unwrap());
+}
+
+#[test]
+fn test_codes_sounds_alike() {
+    let e = AlphaSis::new();
+
+    assert!(e.sounds_alike("Czerny", "Cherny").unwrap());
+    assert!(e.sounds_alike("Cherny", "Czerny").unwrap());
+    assert!(e.sounds_alike("Mitz", "Mits").unwrap());
+    assert!(!e.sounds_alike("Schmidt", "Smith").unwrap());
+    assert!(!e.sounds_alike("Jackson", "Jaxon").unwrap());
+    assert!(!e.sounds_alike("", "").unwrap());
+}
+
+#[test]
+fn test_codes_intersects() {
+    let a = CodeSet::with_alternates(String::from("A1"), vec![String::from("B2")]);
+    let b = CodeSet::with_alternates(String::from("C3"), vec![String::from("B2")]);
+    let c = CodeSet::new(String::from("C3"));
+
+    assert!(a.intersects(&b));
+    assert!(b.intersects(&c));
+    assert!(!a.intersects(&c));
+    assert!(!CodeSet::new(String::new()).intersects(&CodeSet::new(String::new())));
+}
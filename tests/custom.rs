@@ -0,0 +1,203 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{
+    CharacterPolicy, CustomDefinition, CustomEncoder, Lein, Phonics, PhonicsEncoder, PhonicsError,
+};
+
+const LEIN: &str = r#"
+name = "lein_like"
+keep_first_letter = true
+drop = "AEIOUYWH"
+dedup = "letters"
+pad = "0"
+max_code_len = 4
+
+[[codes]]
+letters = "DT"
+code = "1"
+
+[[codes]]
+letters = "MN"
+code = "2"
+
+[[codes]]
+letters = "LR"
+code = "3"
+
+[[codes]]
+letters = "BFPV"
+code = "4"
+
+[[codes]]
+letters = "CJKGQSXZ"
+code = "5"
+"#;
+
+#[test]
+fn test_custom_phonics() {
+    let e = Phonics::<CustomEncoder>::new();
+
+    assert_eq!(e.encode("Hilbert").unwrap(), "HILBERT");
+    assert_eq!(e.encode("O'Brien").unwrap(), "OBRIEN");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_custom_toml() {
+    let e = CustomEncoder::from_toml(LEIN).unwrap();
+    let lein = Lein::new();
+
+    assert_eq!(e.name(), "lein_like");
+    for name in &[
+        "Dubose",
+        "Dubs",
+        "Euler",
+        "Ellery",
+        "Gauss",
+        "Ghosh",
+        "Hilbert",
+        "Knuth",
+        "Kant",
+        "Lloyd",
+        "Lukasiewicz",
+        "Lissajous",
+        "Wikipedia",
+        "Breschnew",
+        "A",
+    ] {
+        assert_eq!(e.encode(name).unwrap(), lein.encode(name).unwrap());
+    }
+}
+
+#[test]
+fn test_custom_json() {
+    let e = CustomEncoder::from_json(
+        r#"{
+            "name": "drug",
+            "normalize": true,
+            "rules": [
+                { "pattern": "PH", "replacement": "F" },
+                { "pattern": "X", "replacement": "Z", "position": "initial" },
+                { "pattern": "E", "replacement": "", "position": "final" },
+                { "pattern": "S", "replacement": "Z", "left": "vowel", "right": "vowel" }
+            ],
+            "keep_first_letter": true,
+            "drop": "AEIOUYHW",
+            "dedup": "codes",
+            "codes": [
+                { "letters": "BFPV", "code": "1" },
+                { "letters": "CGJKQSXZ", "code": "2" },
+                { "letters": "DT", "code": "3" },
+                { "letters": "L", "code": "4" },
+                { "letters": "MN", "code": "5" },
+                { "letters": "R", "code": "6" }
+            ],
+            "max_code_len": 6
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(e.name(), "drug");
+    assert_eq!(e.encode("Xanax").unwrap(), "Z52");
+    assert_eq!(e.encode("Zanax").unwrap(), "Z52");
+    assert_eq!(e.encode("Phenergan").unwrap(), "F5625");
+    assert_eq!(e.encode("Fenergan").unwrap(), "F5625");
+    assert_eq!(e.encode("Ibuprofène").unwrap(), "I1615");
+    assert_eq!(e.encode("Ibuprofen").unwrap(), "I1615");
+    assert_eq!(e.encode("Alprazolam").unwrap(), "A41624");
+}
+
+#[test]
+fn test_custom_max_code_len() {
+    let mut e = CustomEncoder::from_toml(LEIN).unwrap();
+
+    e.max_code_len = 6;
+    assert_eq!(e.encode("Hilbert").unwrap(), "H34310");
+
+    e.max_code_len = 2;
+    assert_eq!(e.encode("Hilbert").unwrap(), "H3");
+}
+
+#[test]
+fn test_custom_policy() {
+    let mut e = CustomEncoder::from_toml(LEIN).unwrap();
+
+    assert_eq!(e.policy, CharacterPolicy::Drop);
+    assert_eq!(e.encode("Ørsted").unwrap(), "R511");
+
+    e.policy = CharacterPolicy::Transliterate;
+    assert_eq!(e.encode("Ørsted").unwrap(), "O351");
+
+    e.policy = CharacterPolicy::Reject;
+    assert!(e.encode("Ørsted").is_err());
+
+    let e = CustomEncoder::from_toml("policy = \"reject\"").unwrap();
+    assert_eq!(e.policy, CharacterPolicy::Reject);
+    assert!(e.encode("Ørsted").is_err());
+}
+
+#[test]
+fn test_custom_definition() {
+    let d = CustomDefinition {
+        keep_first_letter: true,
+        drop: String::from("AEIOU"),
+        ..CustomDefinition::default()
+    };
+    let e = CustomEncoder::from_definition(&d).unwrap();
+    assert_eq!(e.encode("Hilbert").unwrap(), "HLBRT");
+
+    let round_trip: CustomDefinition = toml::from_str(&toml::to_string(&d).unwrap()).unwrap();
+    assert_eq!(round_trip, d);
+}
+
+#[test]
+fn test_custom_invalid() {
+    for definition in &[
+        "alphabet = \"\"",
+        "alphabet = \"abc\"",
+        "drop = \"aeiou\"",
+        "dedup = \"sometimes\"",
+        "pad = \"00\"",
+        "pad = \"0\"",
+        "policy = \"ignore\"",
+        "unknown_field = 1",
+        "max_code_len = \"four\"",
+        "codes = [{ letters = \"\", code = \"1\" }]",
+        "codes = [{ letters = \"DT\", code = \"1\" }, { letters = \"TD\", code = \"2\" }]",
+        "rules = [{ pattern = \"\", replacement = \"F\" }]",
+        "rules = [{ pattern = \"ph\", replacement = \"F\" }]",
+        "rules = [{ pattern = \"PH\", replacement = \"F\", left = \"vowels\" }]",
+        "rules = [{ pattern = \"PH\", replacement = \"F\", position = \"start\" }]",
+        "not toml",
+        "name = \"lein\"",
+        "name = \"soundex\"",
+    ] {
+        match CustomEncoder::from_toml(definition) {
+            Err(PhonicsError::InvalidConfiguration(_)) => {}
+            _ => panic!("the definition {} was accepted", definition),
+        }
+    }
+
+    assert!(CustomEncoder::from_json("{\"dedup\": \"sometimes\"}").is_err());
+    assert!(CustomEncoder::from_json("{").is_err());
+}
@@ -0,0 +1,77 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Czech, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_czech_phonics() {
+    let e = Phonics::<Czech>::new();
+
+    assert_eq!(e.encode("Šimánský").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("Višněvský").unwrap(), "VIXNEFSKI");
+    assert_eq!(e.encode("Kovalský").unwrap(), "KOVALSKI");
+}
+
+#[test]
+fn test_czech_directly() {
+    let e = Czech::new();
+
+    assert_eq!(e.encode("Šimánský").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("Višněvský").unwrap(), "VIXNEFSKI");
+    assert_eq!(e.encode("Kovalský").unwrap(), "KOVALSKI");
+    assert_eq!(e.encode("Novák").unwrap(), "NOVAK");
+    assert_eq!(e.encode("Nowak").unwrap(), "NOVAK");
+    assert_eq!(e.encode("Řepka").unwrap(), "WEPKA");
+    assert_eq!(e.encode("Žepka").unwrap(), "WEPKA");
+    assert_eq!(e.encode("Černý").unwrap(), "QERNI");
+    assert_eq!(e.encode("Dvořák").unwrap(), "DVOWAK");
+    assert_eq!(e.encode("Kříž").unwrap(), "KXIX");
+    assert_eq!(e.encode("Chrudim").unwrap(), "HRUDIM");
+    assert_eq!(e.encode("Hrubý").unwrap(), "HRUBI");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+}
+
+#[test]
+fn test_czech_max_code_len() {
+    let mut e = Czech::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Šimánský").unwrap(), "XIMA");
+    assert_eq!(e.encode("Kříž").unwrap(), "KXIX");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_czech_policy() {
+    let mut e = Czech::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Šimánský").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Nový 2").is_err());
+    assert!(e.encode("Wiśniewski").is_err());
+
+    e.policy = CharacterPolicy::Transliterate;
+    assert_eq!(e.encode("Šimánský").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("Wiśniewski").unwrap(), "VISNIEFSKI");
+}
@@ -0,0 +1,83 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Davidson, Phonics, PhonicsEncoder, PhonicsError, UnknownCharacter};
+
+#[test]
+fn test_davidson_phonics() {
+    let e = Phonics::<Davidson>::new();
+
+    assert_eq!(e.encode("Howard, James").unwrap(), "HRD J");
+    assert_eq!(e.encode("Mulder, Fox").unwrap(), "MLDRF");
+    assert_eq!(e.encode("Scully, Dana").unwrap(), "SCL D");
+}
+
+#[test]
+fn test_davidson_directly() {
+    let e = Davidson::new();
+
+    assert_eq!(e.encode("Howard, James").unwrap(), "HRD J");
+    assert_eq!(e.encode("Mulder, Fox").unwrap(), "MLDRF");
+    assert_eq!(e.encode("Scully, Dana").unwrap(), "SCL D");
+    assert_eq!(e.encode("Brown, John").unwrap(), "BRN J");
+    assert_eq!(e.encode("Braun, Jon").unwrap(), "BRN J");
+    assert_eq!(e.encode("Gough, A").unwrap(), "G   A");
+    assert_eq!(e.encode("McDonald, Ian").unwrap(), "MCDNI");
+    assert_eq!(e.encode("Lee").unwrap(), "L    ");
+    assert_eq!(e.encode(", James").unwrap(), "");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_davidson_encode_name() {
+    let e = Davidson::new();
+
+    assert_eq!(e.encode_name("Howard", "James").unwrap(), "HRD J");
+    assert_eq!(e.encode_name("Mulder", "Fox").unwrap(), "MLDRF");
+    assert_eq!(e.encode_name("Lee", "").unwrap(), "L    ");
+    assert_eq!(e.encode_name("O'Brien", "Mary").unwrap(), "OBRNM");
+    assert_eq!(e.encode_name("", "James").unwrap(), "");
+}
+
+#[test]
+fn test_davidson_policy() {
+    let mut e = Davidson::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Howard, James").unwrap(), "HRD J");
+    assert_eq!(e.encode("Lee").unwrap(), "L    ");
+    assert_eq!(
+        e.encode("Lee2").unwrap_err(),
+        PhonicsError::UnknownCharactersFound(vec![UnknownCharacter {
+            character: '2',
+            byte_offset: 3,
+            char_offset: 3,
+        }])
+    );
+    assert_eq!(e.encode_name("Lee", "").unwrap(), "L    ");
+    assert!(e.encode_name("O'Brien", "Mary").is_err());
+    assert!(e.encode_name("Muller", "Jürgen").is_err());
+
+    e.policy = CharacterPolicy::Transliterate;
+    assert_eq!(e.encode("Ødegaard, Jürgen").unwrap(), "ODGRJ");
+    assert_eq!(e.encode_name("Ødegaard", "Jürgen").unwrap(), "ODGRJ");
+}
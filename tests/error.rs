@@ -0,0 +1,105 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use std::error::Error;
+
+use phonics::{CharacterPolicy, Davidson, Lein, PhonicsEncoder, PhonicsError, UnknownCharacter};
+
+fn rejecting_lein() -> Lein {
+    let mut e = Lein::new();
+
+    e.policy = CharacterPolicy::Reject;
+    e
+}
+
+#[test]
+fn test_error_unknown_characters() {
+    let e = rejecting_lein();
+
+    assert_eq!(
+        e.encode("Mü ller").unwrap_err(),
+        PhonicsError::UnknownCharactersFound(vec![
+            UnknownCharacter {
+                character: 'ü',
+                byte_offset: 1,
+                char_offset: 1,
+            },
+            UnknownCharacter {
+                character: ' ',
+                byte_offset: 3,
+                char_offset: 2,
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_error_davidson_offsets() {
+    let mut e = Davidson::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(
+        e.encode("Muller, Jürgen").unwrap_err(),
+        PhonicsError::UnknownCharactersFound(vec![UnknownCharacter {
+            character: 'ü',
+            byte_offset: 9,
+            char_offset: 9,
+        }])
+    );
+    assert_eq!(
+        e.encode_name("Muller", "Jürgen").unwrap_err(),
+        PhonicsError::UnknownCharactersFound(vec![UnknownCharacter {
+            character: 'ü',
+            byte_offset: 1,
+            char_offset: 1,
+        }])
+    );
+}
+
+#[test]
+fn test_error_display() {
+    let e = rejecting_lein();
+
+    assert_eq!(
+        e.encode("Müller 2").unwrap_err().to_string(),
+        "unknown characters found: 'ü' at byte 1 (character 1), ' ' at byte 7 (character 6), \
+         '2' at byte 8 (character 7)"
+    );
+    assert_eq!(
+        PhonicsError::InvalidConfiguration(String::from("bad")).to_string(),
+        "invalid configuration: bad"
+    );
+    assert_eq!(PhonicsError::EmptyInput.to_string(), "empty input");
+}
+
+#[test]
+fn test_error_question_mark() {
+    fn encode(word: &str) -> Result<String, Box<dyn Error>> {
+        Ok(rejecting_lein().encode(word)?)
+    }
+
+    assert_eq!(encode("Hilbert").unwrap(), "H343");
+    assert!(encode("Müller")
+        .unwrap_err()
+        .to_string()
+        .starts_with("unknown characters found"));
+}
@@ -0,0 +1,97 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Greek, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_greek_phonics() {
+    let e = Phonics::<Greek>::new();
+
+    assert_eq!(e.encode("Παπαδόπουλος").unwrap(), "PAPADOPULOS");
+    assert_eq!(e.encode("Papadopoulos").unwrap(), "PAPADOPULOS");
+    assert_eq!(e.encode("Μπάμπης").unwrap(), "BABIS");
+}
+
+#[test]
+fn test_greek_directly() {
+    let e = Greek::new();
+
+    assert_eq!(e.encode("Παπαδόπουλος").unwrap(), "PAPADOPULOS");
+    assert_eq!(e.encode("Papadopoulos").unwrap(), "PAPADOPULOS");
+    assert_eq!(e.encode("Papadopulos").unwrap(), "PAPADOPULOS");
+    assert_eq!(e.encode("Αικατερίνη").unwrap(), "EKATERINI");
+    assert_eq!(e.encode("Aikaterini").unwrap(), "EKATERINI");
+    assert_eq!(e.encode("Ekaterini").unwrap(), "EKATERINI");
+    assert_eq!(e.encode("Μπάμπης").unwrap(), "BABIS");
+    assert_eq!(e.encode("Mpampis").unwrap(), "BABIS");
+    assert_eq!(e.encode("Babis").unwrap(), "BABIS");
+    assert_eq!(e.encode("Κωνσταντίνος").unwrap(), "KONSTADINOS");
+    assert_eq!(e.encode("Constantinos").unwrap(), "KONSTADINOS");
+    assert_eq!(e.encode("Ευάγγελος").unwrap(), "EVAGELOS");
+    assert_eq!(e.encode("Evangelos").unwrap(), "EVAGELOS");
+    assert_eq!(e.encode("Evaggelos").unwrap(), "EVAGELOS");
+    assert_eq!(e.encode("Χατζής").unwrap(), "HATZIS");
+    assert_eq!(e.encode("Hatzis").unwrap(), "HATZIS");
+    assert_eq!(e.encode("Ξενάκης").unwrap(), "XENAKIS");
+    assert_eq!(e.encode("Ksenakis").unwrap(), "XENAKIS");
+    assert_eq!(e.encode("Φιλίππου").unwrap(), "FILIPU");
+    assert_eq!(e.encode("Philippou").unwrap(), "FILIPU");
+    assert_eq!(e.encode("Οικονόμου").unwrap(), "IKONOMU");
+    assert_eq!(e.encode("Ikonomou").unwrap(), "IKONOMU");
+    assert_eq!(e.encode("Ευρυδίκη").unwrap(), "EVRIDIKI");
+    assert_eq!(e.encode("Evrydiki").unwrap(), "EVRIDIKI");
+    assert_eq!(e.encode("Ντίνος").unwrap(), "DINOS");
+    assert_eq!(e.encode("Dinos").unwrap(), "DINOS");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_greek_diaeresis() {
+    let e = Greek::new();
+
+    assert_eq!(e.encode("Αϊδίνης").unwrap(), "AIDINIS");
+    assert_eq!(e.encode("ΑΪΔΊΝΗΣ").unwrap(), "AIDINIS");
+    assert_eq!(e.encode("Aïdinis").unwrap(), "AIDINIS");
+    assert_eq!(e.encode("Aidinis").unwrap(), "EDINIS");
+}
+
+#[test]
+fn test_greek_max_code_len() {
+    let mut e = Greek::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Παπαδόπουλος").unwrap(), "PAPA");
+    assert_eq!(e.encode("Dinos").unwrap(), "DINO");
+    assert_eq!(e.encode("Μπάμπης").unwrap(), "BABI");
+}
+
+#[test]
+fn test_greek_policy() {
+    let mut e = Greek::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Αϊδίνης").unwrap(), "AIDINIS");
+    assert_eq!(e.encode("Babis").unwrap(), "BABIS");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Παπαδόπουλος-Νίκας").is_err());
+    assert!(e.encode("Babis 2").is_err());
+}
@@ -0,0 +1,101 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Hebrew, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_hebrew_phonics() {
+    let e = Phonics::<Hebrew>::new();
+
+    assert_eq!(e.encode("Chaim").unwrap(), "KM");
+    assert_eq!(e.encode("חיים").unwrap(), "KM");
+    assert_eq!(e.encode("Yosef").unwrap(), "SP");
+}
+
+#[test]
+fn test_hebrew_directly() {
+    let e = Hebrew::new();
+
+    assert_eq!(e.encode("Chaim").unwrap(), "KM");
+    assert_eq!(e.encode("Haim").unwrap(), "KM");
+    assert_eq!(e.encode("Chayim").unwrap(), "KM");
+    assert_eq!(e.encode("חיים").unwrap(), "KM");
+    assert_eq!(e.encode("חַיִּים").unwrap(), "KM");
+    assert_eq!(e.encode("Yosef").unwrap(), "SP");
+    assert_eq!(e.encode("Joseph").unwrap(), "SP");
+    assert_eq!(e.encode("יוסף").unwrap(), "SP");
+    assert_eq!(e.encode("Cohen").unwrap(), "KN");
+    assert_eq!(e.encode("Kahn").unwrap(), "KN");
+    assert_eq!(e.encode("כֹּהֵן").unwrap(), "KN");
+    assert_eq!(e.encode("Abraham").unwrap(), "BRKM");
+    assert_eq!(e.encode("Avraham").unwrap(), "BRKM");
+    assert_eq!(e.encode("אַבְרָהָם").unwrap(), "BRKM");
+    assert_eq!(e.encode("Hannah").unwrap(), "KN");
+    assert_eq!(e.encode("Sarah").unwrap(), "SR");
+    assert_eq!(e.encode("Chana").unwrap(), "KN");
+    assert_eq!(e.encode("חנה").unwrap(), "KN");
+    assert_eq!(e.encode("Moshe").unwrap(), "MS");
+    assert_eq!(e.encode("Moses").unwrap(), "MS");
+    assert_eq!(e.encode("משה").unwrap(), "MS");
+    assert_eq!(e.encode("Rebecca").unwrap(), "RBK");
+    assert_eq!(e.encode("Rivka").unwrap(), "RBK");
+    assert_eq!(e.encode("רבקה").unwrap(), "RBK");
+    assert_eq!(e.encode("Katz").unwrap(), "KZ");
+    assert_eq!(e.encode("כץ").unwrap(), "KZ");
+    assert_eq!(e.encode("Vered").unwrap(), "BRD");
+    assert_eq!(e.encode("ורד").unwrap(), "BRD");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_hebrew_yiddish() {
+    let e = Hebrew::new();
+
+    assert_eq!(e.encode("Wolf").unwrap(), "BLP");
+    assert_eq!(e.encode("Volf").unwrap(), "BLP");
+    assert_eq!(e.encode("וואלף").unwrap(), "BLP");
+    assert_eq!(e.encode("װאָלף").unwrap(), "BLP");
+    assert_eq!(e.encode("Schneerson").unwrap(), "SNRSN");
+    assert_eq!(e.encode("Shneurson").unwrap(), "SNRSN");
+}
+
+#[test]
+fn test_hebrew_max_code_len() {
+    let mut e = Hebrew::new();
+
+    e.max_code_len = 3;
+    assert_eq!(e.encode("Ben-Gurion").unwrap(), "BNG");
+    assert_eq!(e.encode("בן־גוריון").unwrap(), "BNG");
+    assert_eq!(e.encode("Chaim").unwrap(), "KM");
+}
+
+#[test]
+fn test_hebrew_policy() {
+    let mut e = Hebrew::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("בן־גוריון").unwrap(), "BNGRN");
+    assert_eq!(e.encode("Ben-Gurion").unwrap(), "BNGRN");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Cohen 2").is_err());
+    assert!(e.encode("Коган").is_err());
+}
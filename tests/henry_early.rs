@@ -0,0 +1,82 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, HenryEarly, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_henry_early_phonics() {
+    let e = Phonics::<HenryEarly>::new();
+
+    assert_eq!(e.encode("Marchand").unwrap(), "MRC");
+    assert_eq!(e.encode("Beaulieu").unwrap(), "BL");
+    assert_eq!(e.encode("Beaumont").unwrap(), "BM");
+}
+
+#[test]
+fn test_henry_early_directly() {
+    let e = HenryEarly::new();
+
+    assert_eq!(e.encode("Marchand").unwrap(), "MRC");
+    assert_eq!(e.encode("Beaulieu").unwrap(), "BL");
+    assert_eq!(e.encode("Beaumont").unwrap(), "BM");
+    assert_eq!(e.encode("Legrand").unwrap(), "LGR");
+    assert_eq!(e.encode("Pelletier").unwrap(), "PLT");
+    assert_eq!(e.encode("Dupont").unwrap(), "DP");
+    assert_eq!(e.encode("Dupond").unwrap(), "DP");
+    assert_eq!(e.encode("Renault").unwrap(), "RN");
+    assert_eq!(e.encode("Arnault").unwrap(), "ARN");
+    assert_eq!(e.encode("Saint-Martin").unwrap(), "XMR");
+    assert_eq!(e.encode("Chrétien").unwrap(), "KRT");
+    assert_eq!(e.encode("Philippe").unwrap(), "FLP");
+    assert_eq!(e.encode("Lefèvre").unwrap(), "LFV");
+    assert_eq!(e.encode("Lefebvre").unwrap(), "LFV");
+    assert_eq!(e.encode("Françoise").unwrap(), "FRS");
+    assert_eq!(e.encode("Aubert").unwrap(), "OBR");
+    assert_eq!(e.encode("Emmanuel").unwrap(), "AMN");
+    assert_eq!(e.encode("Yves").unwrap(), "IV");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_henry_early_max_code_len() {
+    let mut e = HenryEarly::new();
+
+    e.max_code_len = 2;
+    assert_eq!(e.encode("Marchand").unwrap(), "MR");
+    assert_eq!(e.encode("Beaulieu").unwrap(), "BL");
+
+    e.max_code_len = 0;
+    assert_eq!(e.encode("Beaumarchais").unwrap(), "BMRC");
+    assert_eq!(e.encode("Lefebvre").unwrap(), "LFVR");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_henry_early_policy() {
+    let mut e = HenryEarly::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Chrétien").unwrap(), "KRT");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Saint-Martin").is_err());
+    assert!(e.encode("Müller 2").is_err());
+}
@@ -0,0 +1,79 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Indian, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_indian_phonics() {
+    let e = Phonics::<Indian>::new();
+
+    assert_eq!(e.encode("Shrivastava").unwrap(), "SRVSTV");
+    assert_eq!(e.encode("Bhatt").unwrap(), "BT");
+    assert_eq!(e.encode("Laxmi").unwrap(), "LKSM");
+}
+
+#[test]
+fn test_indian_directly() {
+    let e = Indian::new();
+
+    assert_eq!(e.encode("Shrivastava").unwrap(), "SRVSTV");
+    assert_eq!(e.encode("Srivastav").unwrap(), "SRVSTV");
+    assert_eq!(e.encode("Bhatt").unwrap(), "BT");
+    assert_eq!(e.encode("Bhat").unwrap(), "BT");
+    assert_eq!(e.encode("Laxmi").unwrap(), "LKSM");
+    assert_eq!(e.encode("Lakshmi").unwrap(), "LKSM");
+    assert_eq!(e.encode("Chowdhury").unwrap(), "CDR");
+    assert_eq!(e.encode("Chaudhary").unwrap(), "CDR");
+    assert_eq!(e.encode("Feroze").unwrap(), "FRJ");
+    assert_eq!(e.encode("Pheroze").unwrap(), "FRJ");
+    assert_eq!(e.encode("Zaveri").unwrap(), "JVR");
+    assert_eq!(e.encode("Javeri").unwrap(), "JVR");
+    assert_eq!(e.encode("Kumar").unwrap(), "KMR");
+    assert_eq!(e.encode("Coomar").unwrap(), "KMR");
+    assert_eq!(e.encode("Gupta").unwrap(), "GPT");
+    assert_eq!(e.encode("Guptha").unwrap(), "GPT");
+    assert_eq!(e.encode("Eeshan").unwrap(), "ISN");
+    assert_eq!(e.encode("Ishan").unwrap(), "ISN");
+    assert_eq!(e.encode("Vijay").unwrap(), "VJ");
+    assert_eq!(e.encode("Vijai").unwrap(), "VJ");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_indian_max_code_len() {
+    let mut e = Indian::new();
+
+    e.max_code_len = 3;
+    assert_eq!(e.encode("Shrivastava").unwrap(), "SRV");
+    assert_eq!(e.encode("Bhatt").unwrap(), "BT");
+}
+
+#[test]
+fn test_indian_policy() {
+    let mut e = Indian::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Lakshmi").unwrap(), "LKSM");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Ram Prasad").is_err());
+    assert!(e.encode("Bhatt-2").is_err());
+}
@@ -0,0 +1,79 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Italian, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_italian_phonics() {
+    let e = Phonics::<Italian>::new();
+
+    assert_eq!(e.encode("Ciccone").unwrap(), "CIKONE");
+    assert_eq!(e.encode("Gianni").unwrap(), "JANI");
+    assert_eq!(e.encode("Scotti").unwrap(), "SKOTI");
+}
+
+#[test]
+fn test_italian_directly() {
+    let e = Italian::new();
+
+    assert_eq!(e.encode("Ciccone").unwrap(), "CIKONE");
+    assert_eq!(e.encode("Gianni").unwrap(), "JANI");
+    assert_eq!(e.encode("Janni").unwrap(), "JANI");
+    assert_eq!(e.encode("Scotti").unwrap(), "SKOTI");
+    assert_eq!(e.encode("Scoti").unwrap(), "SKOTI");
+    assert_eq!(e.encode("Sciascia").unwrap(), "XAXA");
+    assert_eq!(e.encode("Scheggia").unwrap(), "SKEJA");
+    assert_eq!(e.encode("Chiesa").unwrap(), "KIESA");
+    assert_eq!(e.encode("Cecchi").unwrap(), "CEKI");
+    assert_eq!(e.encode("Guglielmo").unwrap(), "GULELMO");
+    assert_eq!(e.encode("Puglia").unwrap(), "PULA");
+    assert_eq!(e.encode("Agnelli").unwrap(), "ANELI");
+    assert_eq!(e.encode("Gnocchi").unwrap(), "NOKI");
+    assert_eq!(e.encode("Acquaviva").unwrap(), "AKUAVIVA");
+    assert_eq!(e.encode("Niccolò").unwrap(), "NIKOLO");
+    assert_eq!(e.encode("Nicolo").unwrap(), "NIKOLO");
+    assert_eq!(e.encode("Fabbrì").unwrap(), "FABRI");
+    assert_eq!(e.encode("De Luca").unwrap(), "DELUKA");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_italian_max_code_len() {
+    let mut e = Italian::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Ciccone").unwrap(), "CIKO");
+    assert_eq!(e.encode("Gianni").unwrap(), "JANI");
+    assert_eq!(e.encode("Pò").unwrap(), "PO");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_italian_policy() {
+    let mut e = Italian::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Niccolò").unwrap(), "NIKOLO");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("De Luca").is_err());
+    assert!(e.encode("D'Annunzio").is_err());
+}
@@ -0,0 +1,88 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Japanese, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_japanese_phonics() {
+    let e = Phonics::<Japanese>::new();
+
+    assert_eq!(e.encode("Ohno").unwrap(), "ONO");
+    assert_eq!(e.encode("オオノ").unwrap(), "ONO");
+    assert_eq!(e.encode("おおの").unwrap(), "ONO");
+}
+
+#[test]
+fn test_japanese_directly() {
+    let e = Japanese::new();
+
+    assert_eq!(e.encode("Ohno").unwrap(), "ONO");
+    assert_eq!(e.encode("Ono").unwrap(), "ONO");
+    assert_eq!(e.encode("Oono").unwrap(), "ONO");
+    assert_eq!(e.encode("Ōno").unwrap(), "ONO");
+    assert_eq!(e.encode("オオノ").unwrap(), "ONO");
+    assert_eq!(e.encode("おおの").unwrap(), "ONO");
+    assert_eq!(e.encode("Satō").unwrap(), "SATO");
+    assert_eq!(e.encode("Satou").unwrap(), "SATO");
+    assert_eq!(e.encode("Satoh").unwrap(), "SATO");
+    assert_eq!(e.encode("サトウ").unwrap(), "SATO");
+    assert_eq!(e.encode("Tukuda").unwrap(), "TSUKUDA");
+    assert_eq!(e.encode("つくだ").unwrap(), "TSUKUDA");
+    assert_eq!(e.encode("Husimi").unwrap(), "FUSHIMI");
+    assert_eq!(e.encode("Fushimi").unwrap(), "FUSHIMI");
+    assert_eq!(e.encode("Syunsuke").unwrap(), "SHUNSUKE");
+    assert_eq!(e.encode("Shunsuke").unwrap(), "SHUNSUKE");
+    assert_eq!(e.encode("しゅんすけ").unwrap(), "SHUNSUKE");
+    assert_eq!(e.encode("Tiyoda").unwrap(), "CHIYODA");
+    assert_eq!(e.encode("Homma").unwrap(), "HONMA");
+    assert_eq!(e.encode("ほんま").unwrap(), "HONMA");
+    assert_eq!(e.encode("Hattori").unwrap(), "HATORI");
+    assert_eq!(e.encode("はっとり").unwrap(), "HATORI");
+    assert_eq!(e.encode("Shin'ichi").unwrap(), "SHINICHI");
+    assert_eq!(e.encode("しんいち").unwrap(), "SHINICHI");
+    assert_eq!(e.encode("Inoue").unwrap(), "INOUE");
+    assert_eq!(e.encode("Ohara").unwrap(), "OHARA");
+    assert_eq!(e.encode("Yūko").unwrap(), "YUKO");
+    assert_eq!(e.encode("ゆうこ").unwrap(), "YUKO");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_japanese_max_code_len() {
+    let mut e = Japanese::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Tukuda").unwrap(), "TSUK");
+    assert_eq!(e.encode("おおの").unwrap(), "ONO");
+}
+
+#[test]
+fn test_japanese_policy() {
+    let mut e = Japanese::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Ōno").unwrap(), "ONO");
+    assert_eq!(e.encode("さとう").unwrap(), "SATO");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Shin'ichi").is_err());
+    assert!(e.encode("大野").is_err());
+}
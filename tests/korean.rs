@@ -0,0 +1,107 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Korean, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_korean_phonics() {
+    let e = Phonics::<Korean>::new();
+
+    assert_eq!(e.encode("Lee").unwrap(), "I");
+    assert_eq!(e.encode("Park").unwrap(), "PAK");
+    assert_eq!(e.encode("박").unwrap(), "PAK");
+}
+
+#[test]
+fn test_korean_directly() {
+    let e = Korean::new();
+
+    assert_eq!(e.encode("Lee").unwrap(), "I");
+    assert_eq!(e.encode("Yi").unwrap(), "I");
+    assert_eq!(e.encode("Rhee").unwrap(), "I");
+    assert_eq!(e.encode("이").unwrap(), "I");
+    assert_eq!(e.encode("Park").unwrap(), "PAK");
+    assert_eq!(e.encode("Pak").unwrap(), "PAK");
+    assert_eq!(e.encode("Bak").unwrap(), "PAK");
+    assert_eq!(e.encode("박").unwrap(), "PAK");
+    assert_eq!(e.encode("Kim").unwrap(), "KIM");
+    assert_eq!(e.encode("Gim").unwrap(), "KIM");
+    assert_eq!(e.encode("김").unwrap(), "KIM");
+    assert_eq!(e.encode("Choi").unwrap(), "COE");
+    assert_eq!(e.encode("Ch'oe").unwrap(), "COE");
+    assert_eq!(e.encode("최").unwrap(), "COE");
+    assert_eq!(e.encode("Jung").unwrap(), "CUNG");
+    assert_eq!(e.encode("Chŏng").unwrap(), "CUNG");
+    assert_eq!(e.encode("Jeong").unwrap(), "CUNG");
+    assert_eq!(e.encode("정").unwrap(), "CUNG");
+    assert_eq!(e.encode("Suh").unwrap(), "SU");
+    assert_eq!(e.encode("Sŏ").unwrap(), "SU");
+    assert_eq!(e.encode("서").unwrap(), "SU");
+    assert_eq!(e.encode("Oh").unwrap(), "O");
+    assert_eq!(e.encode("Ahn").unwrap(), "AN");
+    assert_eq!(e.encode("Gwon").unwrap(), "KWON");
+    assert_eq!(e.encode("Kwon").unwrap(), "KWON");
+    assert_eq!(e.encode("Ryu").unwrap(), "YU");
+    assert_eq!(e.encode("Haryong").unwrap(), "HALYONG");
+    assert_eq!(e.encode("Gang").unwrap(), "KANG");
+    assert_eq!(e.encode("Ahn").unwrap(), "AN");
+    assert_eq!(e.encode("Yoo").unwrap(), "YU");
+    assert_eq!(e.encode("류").unwrap(), "YU");
+    assert_eq!(e.encode("Roh").unwrap(), "NO");
+    assert_eq!(e.encode("노").unwrap(), "NO");
+    assert_eq!(e.encode("Rim").unwrap(), "IM");
+    assert_eq!(e.encode("Lim").unwrap(), "IM");
+    assert_eq!(e.encode("Kyung").unwrap(), "KYUNG");
+    assert_eq!(e.encode("경").unwrap(), "KYUNG");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_korean_full_names() {
+    let e = Korean::new();
+
+    assert_eq!(e.encode("Kim Jong-un").unwrap(), "KIMCONGUN");
+    assert_eq!(e.encode("Kim Jong Un").unwrap(), "KIMCONGUN");
+    assert_eq!(e.encode("Lee Min-ho").unwrap(), "IMINHO");
+    assert_eq!(e.encode("이민호").unwrap(), "IMINHO");
+}
+
+#[test]
+fn test_korean_max_code_len() {
+    let mut e = Korean::new();
+
+    e.max_code_len = 3;
+    assert_eq!(e.encode("Kyung").unwrap(), "KYU");
+    assert_eq!(e.encode("Park").unwrap(), "PAK");
+}
+
+#[test]
+fn test_korean_policy() {
+    let mut e = Korean::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Ch'oe").unwrap(), "COE");
+    assert_eq!(e.encode("박").unwrap(), "PAK");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Park2").is_err());
+    assert!(e.encode("朴").is_err());
+}
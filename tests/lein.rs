@@ -20,7 +20,7 @@
  * SOFTWARE.
  */
 
-use phonics::{Lein, Phonics, PhonicsEncoder};
+use phonics::{CharacterPolicy, Lein, Phonics, PhonicsEncoder};
 
 #[test]
 fn test_lein_phonics() {
@@ -124,10 +124,10 @@ fn test_lein_max_code_len() {
 }
 
 #[test]
-fn test_lein_clean() {
+fn test_lein_policy() {
     let mut e = Lein::new();
 
-    e.clean = true;
+    e.policy = CharacterPolicy::Reject;
     assert_eq!(e.encode("Euler").unwrap(), "E330");
     assert_eq!(e.encode("Ellery").unwrap(), "E330");
     assert_eq!(e.encode("Gauss").unwrap(), "G500");
@@ -148,4 +148,16 @@ fn test_lein_clean() {
     assert_eq!(e.encode("Wikipedia").unwrap(), "W541");
     assert!(e.encode("garçon").is_err());
     assert_eq!(e.encode("Breschnew").unwrap(), "B355");
+
+    e.policy = CharacterPolicy::Drop;
+    assert_eq!(e.encode("Ørsted").unwrap(), "R511");
+    assert_eq!(e.encode("Ñúñez").unwrap(), "E500");
+    assert_eq!(e.encode("Euler3.1415").unwrap(), "E330");
+
+    e.policy = CharacterPolicy::Transliterate;
+    assert_eq!(e.encode("Ørsted").unwrap(), "O351");
+    assert_eq!(e.encode("garçon").unwrap(), "G352");
+    assert_eq!(e.encode("Ñúñez").unwrap(), "N250");
+    assert_eq!(e.encode("Þórðarson").unwrap(), "T313");
+    assert_eq!(e.encode("Euler3.1415").unwrap(), "E330");
 }
@@ -0,0 +1,137 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{
+    Lein, PackedEncoder, Phonics, PhonicsEncoder, PhonicsError, Soundex, SoundexVariant,
+};
+
+const NAMES: &[&str] = &[
+    "Ashcraft",
+    "Bach",
+    "Euler",
+    "Gauss",
+    "Hilbert",
+    "Jackson",
+    "Kant",
+    "Knuth",
+    "Lloyd",
+    "Lukasiewicz",
+    "Pfister",
+    "Robert",
+    "Rupert",
+    "Schwarzenegger",
+    "Tymczak",
+    "Zwicky",
+];
+
+/// Check that every name's key unpacks to its code, and that the keys sort
+/// as the codes do.
+fn check_keys<E: PackedEncoder>(e: &E) {
+    let mut pairs: Vec<(String, u64)> = NAMES
+        .iter()
+        .map(|name| {
+            let code = e.encode(name).unwrap();
+            let key = e.pack(&code).unwrap();
+            assert_eq!(e.unpack(key).unwrap(), code);
+            assert_eq!(e.encode_key::<u64>(name).unwrap(), key);
+            (code, key)
+        })
+        .collect();
+
+    pairs.sort();
+    assert!(pairs.windows(2).all(|w| w[0].1 <= w[1].1));
+}
+
+#[test]
+fn test_packed_phonics() {
+    let e = Phonics::<Lein>::new();
+
+    assert_eq!(e.encode_key::<u16>("Hilbert").unwrap(), 1647);
+    assert_eq!(e.unpack(1647).unwrap(), "H343");
+}
+
+#[test]
+fn test_packed_directly() {
+    let e = Lein::new();
+
+    assert_eq!(e.key_count().unwrap(), 26 * 6 * 6 * 6);
+    assert_eq!(e.pack("A000").unwrap(), 0);
+    assert_eq!(e.pack("Z555").unwrap(), 26 * 6 * 6 * 6 - 1);
+    assert_eq!(
+        u64::from(e.encode_key::<u16>("Knuth").unwrap()),
+        e.pack("K210").unwrap()
+    );
+    assert_eq!(e.encode_key::<u16>(""), Err(PhonicsError::EmptyInput));
+    assert!(matches!(e.pack("K21"), Err(PhonicsError::InvalidCode(_))));
+    assert!(matches!(e.pack("K216"), Err(PhonicsError::InvalidCode(_))));
+    assert!(matches!(e.pack("k210"), Err(PhonicsError::InvalidCode(_))));
+    assert!(matches!(
+        e.unpack(26 * 6 * 6 * 6),
+        Err(PhonicsError::InvalidCode(_))
+    ));
+    check_keys(&e);
+}
+
+#[test]
+fn test_packed_max_code_len() {
+    let mut e = Lein::new();
+
+    e.max_code_len = 5;
+    assert!(e.encode_key::<u16>("Hilbert").is_ok());
+    e.max_code_len = 6;
+    assert!(matches!(
+        e.encode_key::<u16>("Hilbert"),
+        Err(PhonicsError::InvalidConfiguration(_))
+    ));
+    assert_eq!(
+        u64::from(e.encode_key::<u32>("Hilbert").unwrap()),
+        e.pack("H34310").unwrap()
+    );
+    check_keys(&e);
+
+    e.max_code_len = 11;
+    assert!(e.encode_key::<u32>("Hilbert").is_ok());
+    e.max_code_len = 12;
+    assert!(e.encode_key::<u32>("Hilbert").is_err());
+    assert!(e.encode_key::<u64>("Hilbert").is_ok());
+    e.max_code_len = 30;
+    assert!(matches!(
+        e.key_count(),
+        Err(PhonicsError::InvalidConfiguration(_))
+    ));
+
+    e.max_code_len = 1;
+    assert_eq!(e.encode_key::<u8>("Hilbert").unwrap(), 7);
+    check_keys(&e);
+}
+
+#[test]
+fn test_packed_soundex() {
+    for variant in &[SoundexVariant::Census, SoundexVariant::SqlServer] {
+        check_keys(&Soundex::with_variant(*variant));
+    }
+
+    let mut e = Soundex::with_variant(SoundexVariant::MySql);
+    assert!(e.key_count().is_err());
+    e.max_code_len = 4;
+    check_keys(&e);
+}
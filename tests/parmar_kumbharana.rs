@@ -0,0 +1,66 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, ParmarKumbharana, Phonics, PhonicsEncoder};
+
+#[test]
+fn test_parmar_kumbharana_phonics() {
+    let e = Phonics::<ParmarKumbharana>::new();
+
+    assert_eq!(e.encode("Gough").unwrap(), "GF");
+    assert_eq!(e.encode("pneuma").unwrap(), "NM");
+    assert_eq!(e.encode("knight").unwrap(), "NT");
+}
+
+#[test]
+fn test_parmar_kumbharana_directly() {
+    let e = ParmarKumbharana::new();
+
+    assert_eq!(e.encode("Gough").unwrap(), "GF");
+    assert_eq!(e.encode("pneuma").unwrap(), "NM");
+    assert_eq!(e.encode("knight").unwrap(), "NT");
+    assert_eq!(e.encode("trice").unwrap(), "TRS");
+    assert_eq!(e.encode("judge").unwrap(), "JJ");
+    assert_eq!(e.encode("Smith").unwrap(), "SMTH");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+}
+
+#[test]
+fn test_parmar_kumbharana_max_code_len() {
+    let mut e = ParmarKumbharana::new();
+
+    e.max_code_len = 2;
+    assert_eq!(e.encode("trice").unwrap(), "TR");
+    assert_eq!(e.encode("Gough").unwrap(), "GF");
+}
+
+#[test]
+fn test_parmar_kumbharana_policy() {
+    let mut e = ParmarKumbharana::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("knight").unwrap(), "NT");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("O'Brien").is_err());
+    assert!(e.encode("Smith 2").is_err());
+}
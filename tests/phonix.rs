@@ -0,0 +1,85 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Phonics, PhonicsEncoder, Phonix};
+
+#[test]
+fn test_phonix_phonics() {
+    let e = Phonics::<Phonix>::new();
+
+    assert_eq!(e.encode("Christopher").unwrap(), "K6837000");
+    assert_eq!(e.encode("Niall").unwrap(), "N4000000");
+    assert_eq!(e.encode("Smith").unwrap(), "S5300000");
+}
+
+#[test]
+fn test_phonix_directly() {
+    let e = Phonix::new();
+
+    assert_eq!(e.encode("Christopher").unwrap(), "K6837000");
+    assert_eq!(e.encode("Niall").unwrap(), "N4000000");
+    assert_eq!(e.encode("Smith").unwrap(), "S5300000");
+    assert_eq!(e.encode("Schmidt").unwrap(), "S5300000");
+    assert_eq!(e.encode("Knight").unwrap(), "N3000000");
+    assert_eq!(e.encode("Night").unwrap(), "N3000000");
+    assert_eq!(e.encode("Wright").unwrap(), "R3000000");
+    assert_eq!(e.encode("Right").unwrap(), "R3000000");
+    assert_eq!(e.encode("Philips").unwrap(), "F4180000");
+    assert_eq!(e.encode("Filips").unwrap(), "F4180000");
+    assert_eq!(e.encode("Gough").unwrap(), "G7000000");
+    assert_eq!(e.encode("Pfister").unwrap(), "F8300000");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T5220000");
+    assert_eq!(e.encode("Euler").unwrap(), "V4000000");
+    assert_eq!(e.encode("Ygor").unwrap(), "V2000000");
+    assert_eq!(e.encode("Jones").unwrap(), "J5800000");
+    assert_eq!(e.encode("Jackson").unwrap(), "J2850000");
+    assert_eq!(e.encode("Maja").unwrap(), e.encode("Maya").unwrap());
+    assert_eq!(e.encode("A").unwrap(), "V0000000");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+    assert_eq!(e.encode("Euler3.1415").unwrap(), "V4000000");
+}
+
+#[test]
+fn test_phonix_max_code_len() {
+    let mut e = Phonix::new();
+
+    e.max_code_len = 3;
+    assert_eq!(e.encode("Christopher").unwrap(), "K68");
+    assert_eq!(e.encode("Niall").unwrap(), "N40");
+
+    e.max_code_len = 6;
+    assert_eq!(e.encode("Christopher").unwrap(), "K68370");
+    assert_eq!(e.encode("Niall").unwrap(), "N40000");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_phonix_policy() {
+    let mut e = Phonix::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Christopher").unwrap(), "K6837000");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Euler3.1415").is_err());
+    assert!(e.encode("Müller").is_err());
+}
@@ -0,0 +1,116 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Czech, Phonics, PhonicsEncoder, Polish};
+
+#[test]
+fn test_polish_phonics() {
+    let e = Phonics::<Polish>::new();
+
+    assert_eq!(e.encode("Szymański").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("Wiśniewski").unwrap(), "VIXNEFSKI");
+    assert_eq!(e.encode("Kowalski").unwrap(), "KOVALSKI");
+}
+
+#[test]
+fn test_polish_directly() {
+    let e = Polish::new();
+
+    assert_eq!(e.encode("Szymański").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("Wiśniewski").unwrap(), "VIXNEFSKI");
+    assert_eq!(e.encode("Kowalski").unwrap(), "KOVALSKI");
+    assert_eq!(e.encode("Nowak").unwrap(), "NOVAK");
+    assert_eq!(e.encode("Rzepka").unwrap(), "WEPKA");
+    assert_eq!(e.encode("Żepka").unwrap(), "WEPKA");
+    assert_eq!(e.encode("Czerny").unwrap(), "QERNI");
+    assert_eq!(e.encode("Ćerny").unwrap(), "QERNI");
+    assert_eq!(e.encode("Krzysztof").unwrap(), "KXIXTOF");
+    assert_eq!(e.encode("Kwiatkowski").unwrap(), "KFIATKOFSKI");
+    assert_eq!(e.encode("Dworzak").unwrap(), "DVOWAK");
+    assert_eq!(e.encode("Bóg").unwrap(), "BUK");
+    assert_eq!(e.encode("Buk").unwrap(), "BUK");
+    assert_eq!(e.encode("Zięba").unwrap(), "WENBA");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+    assert_eq!(e.encode("Nowak-Jeleński").unwrap(), "NOVAKJELENSKI");
+}
+
+#[test]
+fn test_polish_max_code_len() {
+    let mut e = Polish::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Szymański").unwrap(), "XIMA");
+    assert_eq!(e.encode("Bóg").unwrap(), "BUK");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_polish_policy() {
+    let mut e = Polish::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Szymański").unwrap(), "XIMANSKI");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Nowak-Jeleński").is_err());
+    assert!(e.encode("Dvořák").is_err());
+
+    e.policy = CharacterPolicy::Drop;
+    assert_eq!(e.encode("Müller").unwrap(), "MLER");
+
+    e.policy = CharacterPolicy::Transliterate;
+    assert_eq!(e.encode("Müller").unwrap(), "MULER");
+    assert_eq!(e.encode("Szymański").unwrap(), "XIMANSKI");
+}
+
+#[test]
+fn test_polish_decomposed() {
+    let mut e = Polish::new();
+
+    for policy in &[
+        CharacterPolicy::Reject,
+        CharacterPolicy::Drop,
+        CharacterPolicy::Transliterate,
+    ] {
+        e.policy = *policy;
+        assert_eq!(e.encode("Wis\u{301}niewski").unwrap(), "VIXNEFSKI");
+        assert_eq!(e.encode("Szyman\u{301}ski").unwrap(), "XIMANSKI");
+    }
+}
+
+#[test]
+fn test_polish_czech_collide() {
+    let p = Polish::new();
+    let c = Czech::new();
+
+    assert_eq!(
+        p.encode("Wiśniewski").unwrap(),
+        c.encode("Višněvský").unwrap()
+    );
+    assert_eq!(
+        p.encode("Szymański").unwrap(),
+        c.encode("Šimánský").unwrap()
+    );
+    assert_eq!(p.encode("Dworzak").unwrap(), c.encode("Dvořák").unwrap());
+    assert_eq!(p.encode("Rzepka").unwrap(), c.encode("Řepka").unwrap());
+    assert_eq!(p.encode("Czerny").unwrap(), c.encode("Černý").unwrap());
+}
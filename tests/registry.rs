@@ -0,0 +1,112 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{encoder_by_name, CharacterPolicy, EncoderOptions, Lein, PhonicsEncoder, ALGORITHMS};
+
+#[test]
+fn test_registry_by_name() {
+    let e = encoder_by_name("lein", &EncoderOptions::default()).unwrap();
+
+    assert_eq!(e.encode("Hilbert").unwrap(), "H343");
+    assert_eq!(e.encode("Knuth").unwrap(), "K210");
+
+    let e = encoder_by_name("soundex", &EncoderOptions::default()).unwrap();
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A261");
+
+    let e = encoder_by_name("soundex_oracle", &EncoderOptions::default()).unwrap();
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A261");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T522");
+
+    let e = encoder_by_name("soundex_sql_server", &EncoderOptions::default()).unwrap();
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A226");
+
+    let e = encoder_by_name("soundex_mysql", &EncoderOptions::default()).unwrap();
+    assert_eq!(e.encode("Quadratically").unwrap(), "Q36324");
+
+    let e = encoder_by_name("davidson", &EncoderOptions::default()).unwrap();
+    assert_eq!(e.encode("Howard, James").unwrap(), "HRD J");
+}
+
+#[test]
+fn test_registry_options() {
+    let options = EncoderOptions {
+        max_code_len: Some(6),
+        policy: Some(CharacterPolicy::Reject),
+    };
+    let e = encoder_by_name("lein", &options).unwrap();
+
+    assert_eq!(e.encode("Hilbert").unwrap(), "H34310");
+    assert!(e.encode("Euler3.1415").is_err());
+
+    let options = EncoderOptions {
+        policy: Some(CharacterPolicy::Reject),
+        ..EncoderOptions::default()
+    };
+    let e = encoder_by_name("davidson", &options).unwrap();
+    assert!(e.encode("Howard, James 2").is_err());
+
+    // The variant named keeps its rules under a different length
+    let options = EncoderOptions {
+        max_code_len: Some(4),
+        ..EncoderOptions::default()
+    };
+    let e = encoder_by_name("soundex_mysql", &options).unwrap();
+    assert_eq!(e.encode("Quadratically").unwrap(), "Q363");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T520");
+}
+
+#[test]
+fn test_registry_invalid() {
+    assert!(encoder_by_name("metaphone", &EncoderOptions::default()).is_err());
+    assert!(encoder_by_name("Lein", &EncoderOptions::default()).is_err());
+    assert!(encoder_by_name("", &EncoderOptions::default()).is_err());
+
+    let options = EncoderOptions {
+        max_code_len: Some(4),
+        ..EncoderOptions::default()
+    };
+    assert!(encoder_by_name("davidson", &options).is_err());
+
+    let options = EncoderOptions {
+        max_code_len: Some(0),
+        ..EncoderOptions::default()
+    };
+    assert!(encoder_by_name("lein", &options).is_err());
+    assert!(encoder_by_name("polish", &options).is_ok());
+}
+
+#[test]
+fn test_registry_dyn() {
+    let encoders: Vec<Box<dyn PhonicsEncoder>> = vec![
+        Box::new(Lein::new()),
+        encoder_by_name("lein", &EncoderOptions::default()).unwrap(),
+    ];
+
+    for e in &encoders {
+        assert_eq!(e.encode("Hilbert").unwrap(), "H343");
+    }
+
+    for name in ALGORITHMS {
+        let e = encoder_by_name(name, &EncoderOptions::default()).unwrap();
+        assert_eq!(e.encode("").unwrap(), "");
+    }
+}
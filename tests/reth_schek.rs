@@ -0,0 +1,80 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Phonics, PhonicsEncoder, RethSchek};
+
+#[test]
+fn test_reth_schek_phonics() {
+    let e = Phonics::<RethSchek>::new();
+
+    assert_eq!(e.encode("Joachim").unwrap(), "JOASIM");
+    assert_eq!(e.encode("Christoph").unwrap(), "SRISDOF");
+    assert_eq!(e.encode("Jörg").unwrap(), "JOERG");
+}
+
+#[test]
+fn test_reth_schek_directly() {
+    let e = RethSchek::new();
+
+    assert_eq!(e.encode("Joachim").unwrap(), "JOASIM");
+    assert_eq!(e.encode("Christoph").unwrap(), "SRISDOF");
+    assert_eq!(e.encode("Jörg").unwrap(), "JOERG");
+    assert_eq!(e.encode("Smith").unwrap(), "SMID");
+    assert_eq!(e.encode("Schmidt").unwrap(), "SMID");
+    assert_eq!(e.encode("Meyer").unwrap(), "MAIR");
+    assert_eq!(e.encode("Maier").unwrap(), "MAIR");
+    assert_eq!(e.encode("Mayer").unwrap(), "MAIR");
+    assert_eq!(e.encode("Müller").unwrap(), "MUELR");
+    assert_eq!(e.encode("Straße").unwrap(), "SDRASE");
+    assert_eq!(e.encode("Strasse").unwrap(), "SDRASE");
+    assert_eq!(e.encode("Schneider").unwrap(), "SNAIDR");
+    assert_eq!(e.encode("Hoffmann").unwrap(), "HOFMAN");
+    assert_eq!(e.encode("Philipp").unwrap(), "FILIB");
+    assert_eq!(e.encode("Kessel").unwrap(), "GESL");
+    assert_eq!(e.encode("Hahn").unwrap(), "HAN");
+    assert_eq!(e.encode("Chemnitz").unwrap(), "SEMNIZ");
+    assert_eq!(e.encode("Bach").unwrap(), "BAS");
+    assert_eq!(e.encode("Fuchs").unwrap(), "FUX");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_reth_schek_max_code_len() {
+    let mut e = RethSchek::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Christoph").unwrap(), "SRIS");
+    assert_eq!(e.encode("Meyer").unwrap(), "MAIR");
+    assert_eq!(e.encode("Hahn").unwrap(), "HAN");
+}
+
+#[test]
+fn test_reth_schek_policy() {
+    let mut e = RethSchek::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Jörg").unwrap(), "JOERG");
+    assert_eq!(e.encode("Straße").unwrap(), "SDRASE");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Müller-Lüdenscheidt").is_err());
+    assert!(e.encode("Schmidt 2").is_err());
+}
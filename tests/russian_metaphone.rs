@@ -0,0 +1,79 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Phonics, PhonicsEncoder, RussianMetaphone};
+
+#[test]
+fn test_russian_metaphone_phonics() {
+    let e = Phonics::<RussianMetaphone>::new();
+
+    assert_eq!(e.encode("Иванов").unwrap(), "ИВАН4");
+    assert_eq!(e.encode("Петров").unwrap(), "ПИТР4");
+    assert_eq!(e.encode("Фёдор").unwrap(), "ФИДАР");
+}
+
+#[test]
+fn test_russian_metaphone_directly() {
+    let e = RussianMetaphone::new();
+
+    assert_eq!(e.encode("Иванов").unwrap(), "ИВАН4");
+    assert_eq!(e.encode("Иванова").unwrap(), "ИВАН9");
+    assert_eq!(e.encode("Петров").unwrap(), "ПИТР4");
+    assert_eq!(e.encode("Соколов").unwrap(), "САКАЛ4");
+    assert_eq!(e.encode("Ёлкин").unwrap(), "ИЛК8");
+    assert_eq!(e.encode("Эдуард").unwrap(), "ИДУАРТ");
+    assert_eq!(e.encode("Фёдор").unwrap(), "ФИДАР");
+    assert_eq!(e.encode("Федор").unwrap(), "ФИДАР");
+    assert_eq!(e.encode("Любовь").unwrap(), "ЛУБАФ");
+    assert_eq!(e.encode("Достоевский").unwrap(), "ДАСТА#");
+    assert_eq!(e.encode("Чайковский").unwrap(), "ЧАЙК@");
+    assert_eq!(e.encode("Шевченко").unwrap(), "ШИВЧИ3");
+    assert_eq!(e.encode("Толстой").unwrap(), "ТАЛСТАЙ");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("Ivanov").unwrap(), "");
+    assert_eq!(e.encode("Петров-Водкин").unwrap(), "ПИТРАВАТК8");
+}
+
+#[test]
+fn test_russian_metaphone_max_code_len() {
+    let mut e = RussianMetaphone::new();
+
+    e.max_code_len = 4;
+    assert_eq!(e.encode("Иванов").unwrap(), "ИВАН");
+    assert_eq!(e.encode("Фёдор").unwrap(), "ФИДА");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_russian_metaphone_policy() {
+    let mut e = RussianMetaphone::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Иванов").unwrap(), "ИВАН4");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Ivanov").is_err());
+    assert!(e.encode("Петров-Водкин").is_err());
+
+    e.policy = CharacterPolicy::Transliterate;
+    assert_eq!(e.encode("Иванов").unwrap(), "ИВАН4");
+    assert_eq!(e.encode("Ivanov").unwrap(), "");
+}
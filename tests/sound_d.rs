@@ -0,0 +1,75 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Phonics, PhonicsEncoder, SoundD};
+
+#[test]
+fn test_sound_d_phonics() {
+    let e = Phonics::<SoundD>::new();
+
+    assert_eq!(e.encode("Gough").unwrap(), "2000");
+    assert_eq!(e.encode("pneuma").unwrap(), "5500");
+    assert_eq!(e.encode("knight").unwrap(), "5300");
+}
+
+#[test]
+fn test_sound_d_directly() {
+    let e = SoundD::new();
+
+    assert_eq!(e.encode("Gough").unwrap(), "2000");
+    assert_eq!(e.encode("pneuma").unwrap(), "5500");
+    assert_eq!(e.encode("knight").unwrap(), "5300");
+    assert_eq!(e.encode("trice").unwrap(), "3620");
+    assert_eq!(e.encode("judge").unwrap(), "2200");
+    assert_eq!(e.encode("Xavier").unwrap(), "2160");
+    assert_eq!(e.encode("Whitehead").unwrap(), "3300");
+    assert_eq!(e.encode("Acorn").unwrap(), "2650");
+    assert_eq!(e.encode("Wright").unwrap(), "6300");
+    assert_eq!(e.encode("Robert").unwrap(), "6163");
+    assert_eq!(e.encode("Aeiou").unwrap(), "0000");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+}
+
+#[test]
+fn test_sound_d_max_code_len() {
+    let mut e = SoundD::new();
+
+    e.max_code_len = 2;
+    assert_eq!(e.encode("Robert").unwrap(), "61");
+    assert_eq!(e.encode("Gough").unwrap(), "20");
+
+    e.max_code_len = 8;
+    assert_eq!(e.encode("Robert").unwrap(), "61630000");
+    assert_eq!(e.encode("Washington").unwrap(), "25235000");
+}
+
+#[test]
+fn test_sound_d_policy() {
+    let mut e = SoundD::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("knight").unwrap(), "5300");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("O'Brien").is_err());
+    assert!(e.encode("Smith 2").is_err());
+}
@@ -0,0 +1,142 @@
+/*-
+ * Copyright 2020 James P. Howard, II <jh@jameshoward.us>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ *   The above copyright notice and this permission notice shall be included in
+ *   all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use phonics::{CharacterPolicy, Phonics, PhonicsEncoder, Soundex, SoundexVariant};
+
+#[test]
+fn test_soundex_phonics() {
+    let e = Phonics::<Soundex>::new();
+
+    assert_eq!(e.encode("Robert").unwrap(), "R163");
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A261");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T522");
+}
+
+#[test]
+fn test_soundex_census() {
+    let e = Soundex::with_variant(SoundexVariant::Census);
+
+    assert_eq!(e.encode("Robert").unwrap(), "R163");
+    assert_eq!(e.encode("Rupert").unwrap(), "R163");
+    assert_eq!(e.encode("Rubin").unwrap(), "R150");
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A261");
+    assert_eq!(e.encode("Ashcroft").unwrap(), "A261");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T522");
+    assert_eq!(e.encode("Pfister").unwrap(), "P236");
+    assert_eq!(e.encode("Honeyman").unwrap(), "H555");
+    assert_eq!(e.encode("Jackson").unwrap(), "J250");
+    assert_eq!(e.encode("Washington").unwrap(), "W252");
+    assert_eq!(e.encode("Quadratically").unwrap(), "Q363");
+    assert_eq!(e.encode("Lee").unwrap(), "L000");
+    assert_eq!(e.encode("A").unwrap(), "A000");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert_eq!(e.encode("12345").unwrap(), "");
+    assert_eq!(e.encode("Euler3.1415").unwrap(), "E460");
+}
+
+#[test]
+fn test_soundex_sql_server() {
+    let e = Soundex::with_variant(SoundexVariant::SqlServer);
+
+    assert_eq!(e.encode("Robert").unwrap(), "R163");
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A226");
+    assert_eq!(e.encode("Ashcroft").unwrap(), "A226");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T522");
+    assert_eq!(e.encode("Pfister").unwrap(), "P236");
+    assert_eq!(e.encode("Jackson").unwrap(), "J250");
+    assert_eq!(e.encode("Lee").unwrap(), "L000");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_soundex_mysql() {
+    let e = Soundex::with_variant(SoundexVariant::MySql);
+
+    assert_eq!(e.encode("Robert").unwrap(), "R163");
+    assert_eq!(e.encode("Quadratically").unwrap(), "Q36324");
+    assert_eq!(e.encode("Washington").unwrap(), "W25235");
+    assert_eq!(e.encode("Ashcraft").unwrap(), "A2613");
+    assert_eq!(e.encode("Tymczak").unwrap(), "T520");
+    assert_eq!(e.encode("Jackson").unwrap(), "J500");
+    assert_eq!(e.encode("Hello").unwrap(), "H400");
+    assert_eq!(e.encode("Lee").unwrap(), "L000");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_soundex_max_code_len() {
+    let mut e = Soundex::new();
+
+    e.max_code_len = 3;
+    assert_eq!(e.encode("Robert").unwrap(), "R16");
+    assert_eq!(e.encode("Lee").unwrap(), "L00");
+
+    e.max_code_len = 6;
+    assert_eq!(e.encode("Washington").unwrap(), "W25235");
+    assert_eq!(e.encode("Lee").unwrap(), "L00000");
+
+    e.max_code_len = 0;
+    assert_eq!(e.encode("Washington").unwrap(), "W25235");
+    assert_eq!(e.encode("Lee").unwrap(), "L000");
+    assert_eq!(e.encode("").unwrap(), "");
+}
+
+#[test]
+fn test_soundex_policy() {
+    let mut e = Soundex::new();
+
+    e.policy = CharacterPolicy::Reject;
+    assert_eq!(e.encode("Robert").unwrap(), "R163");
+    assert_eq!(e.encode("").unwrap(), "");
+    assert!(e.encode("Euler3.1415").is_err());
+    assert!(e.encode("Müller").is_err());
+}
+
+#[test]
+fn test_soundex_prefixes() {
+    let e = Soundex::new();
+
+    let codes = e.encode_all("Van Dyke").unwrap();
+    assert_eq!(codes.primary(), "V532");
+    assert_eq!(codes.alternates(), ["D200"]);
+    assert_eq!(e.encode_all("DeLuca").unwrap().alternates(), ["L200"]);
+    assert_eq!(e.encode_all("la-Rosa").unwrap().alternates(), ["R200"]);
+    assert!(e.sounds_alike("Van Dyke", "Dyke").unwrap());
+    assert!(e.encode_all("Vance").unwrap().alternates().is_empty());
+    assert!(e.encode_all("Lee").unwrap().alternates().is_empty());
+    assert!(e.encode_all("Van").unwrap().alternates().is_empty());
+    assert!(e.encode_all("Van ").unwrap().alternates().is_empty());
+
+    // A name in capitals has a prefix only if it is separated
+    assert_eq!(e.encode_all("VAN DYKE").unwrap().alternates(), ["D200"]);
+    assert_eq!(e.encode_all("VanDyke").unwrap().alternates(), ["D200"]);
+    for name in &[
+        "LANE", "DEAN", "DIANA", "VANCE", "CONRAD", "LEWIS", "VANDYKE",
+    ] {
+        assert!(
+            e.encode_all(name).unwrap().alternates().is_empty(),
+            "{}",
+            name
+        );
+    }
+    assert!(!e.sounds_alike("LANE", "NEE").unwrap());
+}